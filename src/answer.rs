@@ -0,0 +1,167 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize, Serializer};
+
+/// The answer to a part of a puzzle. Solutions can return any type that converts into it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integer of any primitive width up to `i128`.
+    Int(i128),
+    /// A single line of text, e.g. `CMZ`.
+    Text(String),
+    /// Multi-line ASCII art, e.g. the letters drawn by a CRT.
+    Art(String),
+    /// The letters read from ASCII art with [`crate::ocr`], along with the art itself.
+    /// Displayed, submitted and stored as the letters.
+    Letters { text: String, art: String },
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(n: $t) -> Self {
+                Answer::Int(n as i128)
+            }
+        })*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.contains('\n') {
+            Answer::Art(s)
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::from(s.to_string())
+    }
+}
+
+impl Answer {
+    /// Parses an answer that was written down as text, e.g. in the answers store:
+    /// canonical integers become [`Answer::Int`], everything else is kept as is.
+    pub fn parse(s: &str) -> Self {
+        match s.parse::<i128>() {
+            Ok(n) if n.to_string() == s => Answer::Int(n),
+            _ => Answer::from(s),
+        }
+    }
+
+    /// The ASCII art of the answer, if it is drawn.
+    pub fn art(&self) -> Option<&str> {
+        match self {
+            Answer::Art(art) | Answer::Letters { art, .. } => Some(art),
+            Answer::Int(_) | Answer::Text(_) => None,
+        }
+    }
+
+    /// Whether both answers are displayed the same, e.g. `Int(42)` matches `Text("42")`,
+    /// or are drawn the same, so letters also match the art they were read from.
+    pub fn matches(&self, other: &Answer) -> bool {
+        self.to_string() == other.to_string()
+            || self.art().is_some_and(|art| other.art() == Some(art))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) | Answer::Art(s) => write!(f, "{}", s),
+            Answer::Letters { text, .. } => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Integers are serialized as numbers, text and art as strings, and letters as just the letters.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => serializer.serialize_i128(*n),
+            Answer::Text(s) | Answer::Art(s) => serializer.serialize_str(s),
+            Answer::Letters { text, .. } => serializer.serialize_str(text),
+        }
+    }
+}
+
+/// Answers can be written as integers or strings.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Integer(i64),
+    String(String),
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match RawAnswer::deserialize(deserializer)? {
+            RawAnswer::Integer(n) => Answer::Int(n as i128),
+            RawAnswer::String(s) => Answer::parse(&s),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from() {
+        assert_eq!(Answer::from(24000u32), Answer::Int(24000));
+        assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+        assert_eq!(Answer::from(2713310158usize), Answer::Int(2713310158));
+        assert_eq!(Answer::from(u64::MAX), Answer::Int(18446744073709551615));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(
+            Answer::from("##..\n..##\n".to_string()),
+            Answer::Art("##..\n..##\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Answer::parse("45000"), Answer::Int(45000));
+        assert_eq!(Answer::parse("-7"), Answer::Int(-7));
+        assert_eq!(Answer::parse("007"), Answer::Text("007".to_string()));
+        assert_eq!(Answer::parse("MCD"), Answer::Text("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::Int(42).matches(&Answer::Text("42".to_string())));
+        assert!(!Answer::Int(42).matches(&Answer::Text("042".to_string())));
+        assert!(Answer::from("#.\n").matches(&Answer::Art("#.\n".to_string())));
+
+        let letters = Answer::Letters {
+            text: "HI".to_string(),
+            art: "#.\n".to_string(),
+        };
+        assert!(letters.matches(&Answer::from("HI")));
+        assert!(Answer::from("#.\n").matches(&letters));
+        assert!(!letters.matches(&Answer::from(".#\n")));
+    }
+
+    #[test]
+    fn test_serialize() {
+        assert_eq!(
+            serde_json::to_string(&vec![
+                Answer::Int(2713310158),
+                Answer::from("CMZ"),
+                Answer::from("#.\n.#\n")
+            ])
+            .unwrap(),
+            r##"[2713310158,"CMZ","#.\n.#\n"]"##
+        );
+    }
+}
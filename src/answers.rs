@@ -0,0 +1,209 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+use crate::runner::PartResult;
+
+/// The known correct answers of a day, stored in `src/y<year>/answers/<day>.toml`:
+///
+/// ```toml
+/// part_one = 24000
+/// part_two = "MCD"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    pub part_one: Option<Answer>,
+    #[serde(default)]
+    pub part_two: Option<Answer>,
+}
+
+impl Answers {
+    /// Loads the answers of `day`. A missing file means no answers are known yet.
+    pub fn load(year: i16, day: u8) -> Result<Self, String> {
+        let path = crate::input::path(year, "answers", day, "toml");
+
+        match fs::read_to_string(&path) {
+            Ok(s) => Answers::parse(&s).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(s)
+    }
+
+    /// Stores `answer` as the answer of `part` of `day`, keeping the other part's answer.
+    pub fn record(year: i16, day: u8, part: u8, answer: &Answer) -> Result<PathBuf, String> {
+        let mut answers = Answers::load(year, day)?;
+        answers.set(part, answer);
+
+        let path = crate::input::path(year, "answers", day, "toml");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+        }
+        fs::write(&path, answers.to_toml()).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        Ok(path)
+    }
+
+    /// Integer answers that fit TOML integers are written as such, everything else as strings.
+    pub fn to_toml(&self) -> String {
+        let mut out = String::new();
+
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(answer) = answer {
+                let value = match answer {
+                    Answer::Int(n) => i64::try_from(*n)
+                        .map_or_else(|_| toml::Value::String(n.to_string()), toml::Value::Integer),
+                    answer => toml::Value::String(answer.to_string()),
+                };
+                out.push_str(&format!("{} = {}\n", key, value));
+            }
+        }
+
+        out
+    }
+
+    pub fn set(&mut self, part: u8, answer: &Answer) {
+        match part {
+            1 => self.part_one = Some(answer.clone()),
+            2 => self.part_two = Some(answer.clone()),
+            _ => {}
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.part_one.as_ref(),
+            2 => self.part_two.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, result: &PartResult) -> Check {
+        match self.get(result.part) {
+            None => Check::Unknown,
+            Some(expected) if result.answer.as_ref().is_some_and(|a| a.matches(expected)) => {
+                Check::Correct
+            }
+            Some(expected) => Check::Wrong {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+/// The outcome of comparing a result against the known answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Check {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+impl Check {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Check::Correct => "✓",
+            Check::Wrong { .. } => "✗",
+            Check::Unknown => "?",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Check::Correct => "correct",
+            Check::Wrong { .. } => "wrong",
+            Check::Unknown => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn result(part: u8, answer: Option<&str>) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.map(Answer::from),
+            elapsed: Duration::ZERO,
+            stats: None,
+            check: None,
+            peak_memory: None,
+            timed_out: false,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("part_one = 24000\npart_two = \"MCD\"\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::Int(24000)));
+        assert_eq!(answers.get(2), Some(&Answer::from("MCD")));
+
+        let answers = Answers::parse("part_one = \"\"\"\n##..\n..##\n\"\"\"\n").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::from("##..\n..##\n")));
+        assert_eq!(answers.get(2), None);
+
+        let answers =
+            Answers::parse("part_one = \"170141183460469231731687303715884105727\"").unwrap();
+        assert_eq!(answers.get(1), Some(&Answer::Int(i128::MAX)));
+
+        assert!(Answers::parse("part_one = [1]").is_err());
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let mut answers = Answers::default();
+        assert_eq!(answers.to_toml(), "");
+
+        answers.set(2, &Answer::from("007"));
+        assert_eq!(answers.to_toml(), "part_two = \"007\"\n");
+
+        answers.set(2, &Answer::Int(i128::from(u64::MAX)));
+        assert_eq!(answers.to_toml(), "part_two = \"18446744073709551615\"\n");
+
+        answers.set(1, &Answer::Int(24000));
+        answers.set(2, &Answer::from("##..\n..##\n"));
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            "part_one = 24000\npart_two = \"\"\"\n##..\n..##\n\"\"\"\n"
+        );
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("part_one = 24000").unwrap();
+        assert_eq!(answers.check(&result(1, Some("24000"))), Check::Correct);
+        assert_eq!(
+            answers.check(&result(1, Some("24001"))),
+            Check::Wrong {
+                expected: Answer::Int(24000)
+            }
+        );
+        assert_eq!(
+            answers.check(&result(1, None)),
+            Check::Wrong {
+                expected: Answer::Int(24000)
+            }
+        );
+        assert_eq!(answers.check(&result(2, Some("1"))), Check::Unknown);
+    }
+}
@@ -0,0 +1,184 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the requests as coming from this repository, as the website asks of tools,
+/// with a link to the `repository` of `Cargo.toml` if it is set.
+fn user_agent() -> String {
+    let name = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    match env!("CARGO_PKG_REPOSITORY") {
+        "" => name.to_string(),
+        repository => format!("{} (+{})", name, repository),
+    }
+}
+
+/// Reads the session cookie from `$AOC_SESSION` or, if that is not set, `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let home = env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .ok_or("could not locate home directory to read the session cookie from")?;
+
+    let path = home.join(".adventofcode.session");
+
+    fs::read_to_string(&path)
+        .map(|session| session.trim().to_string())
+        .map_err(|e| {
+            format!(
+                "could not read session cookie from \"{}\" and $AOC_SESSION is not set: {}",
+                path.display(),
+                e
+            )
+        })
+}
+
+/// A minimal client for the Advent of Code website.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    /// Uses `$AOC_BASE_URL` as the base url if it is set.
+    pub fn new(session: String) -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Client::with_base_url(session, &base_url)
+    }
+
+    pub fn with_base_url(session: String, base_url: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    pub fn input_url(&self, year: i16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn input(&self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&self.input_url(year, day))
+    }
+
+    pub fn puzzle_url(&self, year: i16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    /// Fetches the HTML of the puzzle page. Part two is only included once part one is solved.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, String> {
+        self.get(&self.puzzle_url(year, day))
+    }
+
+    fn get(&self, url: &str) -> Result<String, String> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &user_agent())
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(404, _) => {
+                    format!("{} not found. Is the puzzle unlocked yet?", url)
+                }
+                ureq::Error::Status(400, _) | ureq::Error::Status(500, _) => format!(
+                    "{} was rejected. Is the session cookie valid? It might have expired.",
+                    url
+                ),
+                e => e.to_string(),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|e| format!("could not read response from {}: {}", url, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    /// Serves a single request with the given status and body, returning the request head.
+    fn mock_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            head
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = mock_server("200 OK", "1000\n2000\n");
+        let client = Client::with_base_url("abc123".to_string(), &url);
+
+        assert_eq!(client.input(2022, 1), Ok("1000\n2000\n".to_string()));
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(head.contains("Cookie: session=abc123\r\n"));
+        assert!(head.contains(&format!("User-Agent: {}\r\n", user_agent())));
+    }
+
+    #[test]
+    fn test_input_not_found() {
+        let (url, server) = mock_server("404 Not Found", "");
+        let client = Client::with_base_url("abc123".to_string(), &url);
+
+        let err = client.input(2022, 25).unwrap_err();
+        assert!(err.contains("/2022/day/25/input not found"), "{}", err);
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let (url, server) = mock_server("200 OK", "<html></html>");
+        let client = Client::with_base_url("abc123".to_string(), &url);
+
+        assert_eq!(client.puzzle(2022, 7), Ok("<html></html>".to_string()));
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /2022/day/7 HTTP/1.1\r\n"));
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::runner::serialize_nanos;
+
+/// Time spent running a part before samples are recorded.
+const WARMUP_TIME: Duration = Duration::from_millis(100);
+
+/// Upper bound on recorded samples, so very fast parts don't fill memory within the time budget.
+const MAX_SAMPLES: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Run each part exactly this many times. Takes precedence over `budget`.
+    pub samples: Option<usize>,
+    /// Keep running each part until this much time has been spent on it.
+    pub budget: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            samples: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+impl Config {
+    /// Reads `--bench [--samples <n>] [--budget <ms>]`. Returns `None` if `--bench` is absent.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        if !args.contains("--bench") {
+            return Ok(None);
+        }
+
+        let default = Config::default();
+
+        Ok(Some(Config {
+            samples: args.opt_value_from_str("--samples")?,
+            budget: args
+                .opt_value_from_fn("--budget", |s| s.parse().map(Duration::from_millis))?
+                .unwrap_or(default.budget),
+        }))
+    }
+}
+
+/// Summary of the timings of repeated runs of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub samples: usize,
+    #[serde(rename = "min_ns", serialize_with = "serialize_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "serialize_nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "serialize_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", serialize_with = "serialize_nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs = sorted.iter().map(Duration::as_secs_f64).collect::<Vec<_>>();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Some(Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Warms up, then runs `func` repeatedly as configured. Returns the answer of the first run.
+pub fn bench<T>(func: impl Fn(&str) -> T, input: &str, config: &Config) -> (T, Stats) {
+    let warmup = Instant::now();
+    let answer = func(input);
+    while warmup.elapsed() < WARMUP_TIME {
+        black_box(func(black_box(input)));
+    }
+
+    let mut samples = vec![];
+    let start = Instant::now();
+
+    loop {
+        let timer = Instant::now();
+        black_box(func(black_box(input)));
+        samples.push(timer.elapsed());
+
+        let done = match config.samples {
+            Some(n) => samples.len() >= n,
+            None => start.elapsed() >= config.budget || samples.len() >= MAX_SAMPLES,
+        };
+
+        if done {
+            break;
+        }
+    }
+
+    (answer, Stats::from_samples(&samples).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(v: &[u64]) -> Vec<Duration> {
+        v.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[4, 1, 3, 2])).unwrap();
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean.as_nanos(), 2500);
+        // sample standard deviation of 1, 2, 3, 4 is sqrt(5 / 3)
+        assert_eq!(stats.stddev.as_nanos(), 1291);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+        assert_eq!(
+            Stats::from_samples(&micros(&[7])).unwrap().stddev,
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_bench_samples() {
+        let config = Config {
+            samples: Some(5),
+            ..Config::default()
+        };
+        let (answer, stats) = bench(|input| Some(input.len()), "abc", &config);
+        assert_eq!(answer, Some(3));
+        assert_eq!(stats.samples, 5);
+    }
+}
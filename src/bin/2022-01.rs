@@ -0,0 +1,55 @@
+use advent_of_code::parse::{parse_blocks, parse_lines};
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    calories(input)?.into_iter().max().ok_or(AocError::Unsolved)
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let vals = calories(input)?;
+
+    let mut max_three = [u32::MIN, u32::MIN, u32::MIN];
+
+    for n in vals {
+        if n > max_three[0] {
+            max_three[2] = max_three[1];
+            max_three[1] = max_three[0];
+            max_three[0] = n;
+        } else if n > max_three[1] {
+            max_three[2] = max_three[1];
+            max_three[1] = n;
+        } else if n > max_three[2] {
+            max_three[2] = n;
+        }
+    }
+
+    Ok(max_three.iter().sum())
+}
+
+/// The total calories carried by each elf.
+fn calories(input: &str) -> Result<Vec<u32>, AocError> {
+    parse_blocks(input, |block| {
+        Ok(parse_lines(block, |n| Ok(n.parse::<u32>()?))?
+            .into_iter()
+            .sum())
+    })
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 1, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 1, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 1, 2, part_two);
+    }
+}
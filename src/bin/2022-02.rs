@@ -1,19 +1,14 @@
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| Round::from_str(line, &Part::PartOne).unwrap().score())
-            .sum::<u32>()
-    )
+use advent_of_code::parse::parse_lines;
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let rounds = parse_lines(input, |line| Round::from_str(line, &Part::PartOne))?;
+    Ok(rounds.iter().map(Round::score).sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| Round::from_str(line, &Part::PartTwo).unwrap().score())
-            .sum::<u32>()
-    )
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let rounds = parse_lines(input, |line| Round::from_str(line, &Part::PartTwo))?;
+    Ok(rounds.iter().map(Round::score).sum())
 }
 
 enum Part {
@@ -21,6 +16,7 @@ enum Part {
     PartTwo
 }
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Copy, Clone)]
 enum RPS {
     Rock,
@@ -59,32 +55,34 @@ impl Round {
         }
     }
 
-    fn from_str(s: &str, part: &Part) -> Option<Round> {
-        let v = s.split(" ").collect::<Vec<_>>();
+    fn from_str(s: &str, part: &Part) -> Result<Round, AocError> {
+        let (theirs, ours) = s
+            .split_once(' ')
+            .ok_or_else(|| AocError::parse(s, "expected two shapes"))?;
 
-        let theirs = match v.first().unwrap().to_owned() {
+        let theirs = match theirs {
             "A" => RPS::Rock,
             "B" => RPS::Paper,
             "C" => RPS::Scissors,
-             _  => return None,
+             _  => return Err(AocError::parse(theirs, "expected one of A, B, C")),
         };
 
         let ours = match part {
-            Part::PartOne => match v.last().unwrap().to_owned() {
+            Part::PartOne => match ours {
                 "X" => RPS::Rock,
                 "Y" => RPS::Paper,
                 "Z" => RPS::Scissors,
-                 _  => return None,
+                 _  => return Err(AocError::parse(ours, "expected one of X, Y, Z")),
             },
-            Part::PartTwo => match v.last().unwrap().to_owned() {
+            Part::PartTwo => match ours {
                 "X" => theirs.loser(),
                 "Y" => theirs,
                 "Z" => theirs.victor(),
-                 _  => return None,
+                 _  => return Err(AocError::parse(ours, "expected one of X, Y, Z")),
             }
         };
 
-        Some(Round::new(&theirs, &ours))
+        Ok(Round::new(&theirs, &ours))
     }
 
     fn score(&self) -> u32 {
@@ -112,9 +110,7 @@ impl Round {
 
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::solve!(2022, 2, part_one, part_two);
 }
 
 #[cfg(test)]
@@ -123,13 +119,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+        advent_of_code::examples::check(2022, 2, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+        advent_of_code::examples::check(2022, 2, 2, part_two);
     }
 }
@@ -1,57 +1,50 @@
 use std::collections::HashSet;
 use std::fmt;
 
+use advent_of_code::parse::parse_lines;
+use advent_of_code::AocError;
+
 const LOWERCASE_OFFSET: u32 = b'a' as u32 - 1;
 const UPPERCASE_OFFSET: u32 = b'A' as u32 - 1;
 const ALPHABET_SIZE: u32 = 26;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| {
-                Rucksack::try_from(line)
-                    .unwrap()
-                    .find_overlapping()
-                    .unwrap_or_else(|| panic!("line: {}", line))
-                    .0
-            })
-            .sum::<u32>(),
-    )
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let priorities = parse_lines(input, |line| {
+        Rucksack::try_from(line)?
+            .find_overlapping()
+            .ok_or_else(|| AocError::parse("", "no item is in both compartments"))
+    })?;
+
+    Ok(priorities.into_iter().map(|p| p.0).sum())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| Rucksack::try_from(line).unwrap())
-            .collect::<Vec<_>>()
-            .chunks(3)
-            .map(|group| {
-                group
-                    .iter()
-                    .map(|sack| sack.left.union(&sack.right).collect::<HashSet<_>>())
-                    .reduce(|a, b| a.intersection(&b).cloned().collect())
-                    .unwrap()
-                    .iter()
-                    .cloned()
-                    .map(|p| p.0)
-                    .next()
-                    .unwrap()
-            })
-            .sum::<u32>()
-    )
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let sacks = parse_lines(input, Rucksack::try_from)?;
+    let mut sum = 0;
+
+    for (i, group) in sacks.chunks(3).enumerate() {
+        let badge = group
+            .iter()
+            .map(|sack| sack.left.union(&sack.right).collect::<HashSet<_>>())
+            .reduce(|a, b| a.intersection(&b).cloned().collect())
+            .and_then(|common| common.into_iter().next())
+            .ok_or_else(|| AocError::runtime(format!("group {} has no common item", i + 1)))?;
+
+        sum += badge.0;
+    }
+
+    Ok(sum)
 }
 
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 struct Priority(u32);
 
 impl TryFrom<char> for Priority {
-    type Error = &'static str;
+    type Error = AocError;
 
     fn try_from(c: char) -> Result<Self, Self::Error> {
         if !c.is_ascii() || !c.is_alphabetic() {
-            Err("Invalid character")
+            Err(AocError::parse("", format!("'{}' is not an item", c)))
         } else if c.is_lowercase() {
             Ok(Priority((c as u32) - LOWERCASE_OFFSET))
         } else {
@@ -103,7 +96,7 @@ impl Rucksack {
 }
 
 impl TryFrom<&str> for Rucksack {
-    type Error = &'static str;
+    type Error = AocError;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         let mut left = HashSet::with_capacity(s.len() / 2);
@@ -122,9 +115,7 @@ impl TryFrom<&str> for Rucksack {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::solve!(2022, 3, part_one, part_two);
 }
 
 #[cfg(test)]
@@ -133,8 +124,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
+        advent_of_code::examples::check(2022, 3, 1, part_one);
 
         let line = "wgqJtbJMqZVTwWPZZT";
         assert_eq!(
@@ -145,7 +135,6 @@ mod tests {
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
+        advent_of_code::examples::check(2022, 3, 2, part_two);
     }
 }
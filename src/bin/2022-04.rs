@@ -0,0 +1,104 @@
+use std::ops::RangeInclusive;
+
+use advent_of_code::parse::parse_lines;
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let pairs = parse_lines(input, Pair::try_from)?;
+    Ok(pairs.iter().filter(|pair| pair.has_containment()).count() as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let pairs = parse_lines(input, Pair::try_from)?;
+    Ok(pairs.iter().filter(|pair| pair.has_overlap()).count() as u32)
+}
+
+struct Assignment(RangeInclusive<u32>);
+
+impl TryFrom<&str> for Assignment {
+    type Error = AocError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| AocError::parse(s, "expected \"<start>-<end>\""))?;
+        Ok(Assignment(start.parse::<u32>()?..=end.parse::<u32>()?))
+    }
+}
+
+impl Assignment {
+    fn contains(&self, other: &Assignment) -> bool {
+        other.0.start() >= self.0.start() && other.0.end() <= self.0.end()
+    }
+
+    fn overlaps(&self, other: &Assignment) -> bool {
+        self.0.start() <= other.0.end() && self.0.end() >= other.0.start()
+    }
+}
+
+struct Pair {
+    left: Assignment,
+    right: Assignment
+}
+
+impl TryFrom<&str> for Pair {
+    type Error = AocError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (left, right) = s
+            .split_once(',')
+            .ok_or_else(|| AocError::parse(s, "expected two assignments"))?;
+        let left = Assignment::try_from(left)?;
+        let right = Assignment::try_from(right)?;
+        Ok(Pair::new(left, right))
+    }
+}
+
+impl Pair {
+    fn new(left: Assignment, right: Assignment) -> Self {
+        Pair {
+            left,
+            right
+        }
+    }
+
+    fn has_containment(&self) -> bool {
+        self.left.contains(&self.right) || self.right.contains(&self.left)
+    }
+
+    fn has_overlap(&self) -> bool {
+        self.left.overlaps(&self.right)
+    }
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 4, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 4, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 4, 2, part_two);
+    }
+    #[test]
+    fn test_invalid_pairs() {
+        let error = |input: &str| part_one(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("2-4,6-8\n2-3"),
+            "line 2: could not parse \"2-3\": expected two assignments"
+        );
+        assert_eq!(
+            error("2-4,6-x"),
+            "line 1: could not parse \"2-4,6-x\": invalid digit found in string"
+        );
+    }
+}
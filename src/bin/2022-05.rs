@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+use std::fmt::{self, Display};
+
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::parse::{fixed_width, ints_n, parse_lines};
+use advent_of_code::visualize::{self, Cell, Frame, Rgb, Visualize};
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let (mut supplies, moves) = parse(input)?;
+
+    for m in moves {
+        supplies.do_op(&m, Crane::CrateMover9000)?;
+    }
+
+    let mut s = String::new();
+
+    for stack in supplies.stacks.iter() {
+        if let Some(c) = stack.iter().last() {
+            s.push(*c);
+        }
+    }
+
+    Ok(s)
+}
+
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    let (mut supplies, moves) = parse(input)?;
+
+    for m in moves {
+        supplies.do_op(&m, Crane::CrateMover9001)?;
+    }
+
+    let mut s = String::new();
+
+    for stack in supplies.stacks.iter() {
+        if let Some(c) = stack.iter().last() {
+            s.push(*c);
+        }
+    }
+
+    Ok(s)
+}
+
+fn parse(input: &str) -> Result<(Supplies, Vec<Move>), AocError> {
+    let (diagram, moves) = input.split_once("\n\n").ok_or_else(|| {
+        AocError::parse(
+            input.lines().next().unwrap_or_default(),
+            "expected a stack diagram and the moves, separated by a blank line",
+        )
+        .at_line(1, "")
+    })?;
+
+    let supplies = Supplies::try_from(diagram)?;
+    // the moves start after the diagram and the blank line.
+    let first_line = diagram.lines().count() + 2;
+    let mut moves = parse_lines(moves, Move::try_from).map_err(|e| e.at_line(first_line, ""))?;
+
+    for (i, m) in moves.iter_mut().enumerate() {
+        m.line = first_line + i;
+        for stack in [m.from, m.to] {
+            if stack >= supplies.stacks.len() {
+                return Err(m.error(format!(
+                    "there is no stack {}, only {}",
+                    stack + 1,
+                    supplies.stacks.len()
+                )));
+            }
+        }
+    }
+
+    Ok((supplies, moves))
+}
+
+#[derive(Clone, Copy)]
+enum Crane {
+    CrateMover9000,
+    CrateMover9001,
+}
+
+#[derive(Debug)]
+struct Supplies {
+    stacks: Vec<VecDeque<char>>,
+}
+
+impl Supplies {
+    fn new(stacks: Vec<VecDeque<char>>) -> Self {
+        Supplies { stacks }
+    }
+
+    fn do_op(&mut self, op: &Move, crane: Crane) -> Result<(), AocError> {
+        let from = &mut self.stacks[op.from];
+        let Some(rest) = from.len().checked_sub(op.count) else {
+            return Err(op.error(format!(
+                "stack {} holds only {} crates",
+                op.from + 1,
+                from.len()
+            )));
+        };
+
+        let crates: Vec<char> = match crane {
+            // lifts one crate at a time, so the top crate ends up at the bottom.
+            Crane::CrateMover9000 => from.drain(rest..).rev().collect(),
+            Crane::CrateMover9001 => from.drain(rest..).collect(),
+        };
+        self.stacks[op.to].extend(crates);
+
+        visualize::emit(self);
+        Ok(())
+    }
+}
+
+impl Visualize for Supplies {
+    // draws the stacks like the diagram in the input, including the stack numbers.
+    fn frame(&self) -> Frame {
+        let height = self.stacks.iter().map(VecDeque::len).max().unwrap_or(0);
+        let width = (4 * self.stacks.len()).saturating_sub(1).max(1);
+        let mut frame = Grid::new(width, height + 1, Cell::EMPTY);
+
+        for (i, stack) in self.stacks.iter().enumerate() {
+            let x = 4 * i as isize;
+            for (j, &c) in stack.iter().enumerate() {
+                let y = (height - 1 - j) as isize;
+                frame[Point::new(x, y)] = Cell::new('[', Rgb::GREY);
+                frame[Point::new(x + 1, y)] = Cell::new(c, Rgb::YELLOW);
+                frame[Point::new(x + 2, y)] = Cell::new(']', Rgb::GREY);
+            }
+
+            let number = char::from_digit((i + 1) as u32 % 10, 10).unwrap();
+            frame[Point::new(x + 1, height as isize)] = Cell::new(number, Rgb::WHITE);
+        }
+
+        frame
+    }
+}
+
+impl TryFrom<&str> for Supplies {
+    type Error = AocError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let first = s
+            .lines()
+            .next()
+            .ok_or_else(|| AocError::parse(s, "the stack diagram is empty").at_line(1, ""))?;
+
+        // The length of each line in the stack diagram
+        // is len = 3 * n + (n - 1) = 4n - 1
+        //    => n = (len + 1) / 4
+        let n: usize = (first.len() + 1) / 4;
+        let mut stacks: Vec<VecDeque<char>> = vec![VecDeque::new(); n];
+
+        'outer: for (row, line) in s.lines().enumerate() {
+            for (i, field) in fixed_width(line, 3, 1).enumerate() {
+                let c = field.chars().nth(1).unwrap_or(' ');
+                if c.is_alphabetic() {
+                    stacks
+                        .get_mut(i)
+                        .ok_or_else(|| {
+                            AocError::parse(line, "the row is wider than the first row")
+                                .at_line(row + 1, "")
+                        })?
+                        .push_front(c);
+                } else if c != ' ' {
+                    break 'outer;
+                }
+            }
+        }
+
+        Ok(Supplies::new(stacks))
+    }
+}
+
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+    /// The 1-based line of the input, filled in by [`parse`].
+    line: usize,
+}
+
+impl Move {
+    fn new(count: usize, from: usize, to: usize) -> Self {
+        Move {
+            count,
+            from,
+            to,
+            line: 0,
+        }
+    }
+
+    /// An error about a move that reads fine but cannot be carried out.
+    fn error(&self, reason: String) -> AocError {
+        AocError::parse(&self.to_string(), reason).at_line(self.line, "")
+    }
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+impl TryFrom<&str> for Move {
+    type Error = AocError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if !s.starts_with("move ") {
+            return Err(AocError::parse(
+                s,
+                "expected \"move <count> from <stack> to <stack>\"",
+            ));
+        }
+
+        let [count, from, to] = ints_n::<usize, 3>(s)?;
+        let stack = |n: usize| {
+            n.checked_sub(1)
+                .ok_or_else(|| AocError::parse(s, "stacks are numbered from 1"))
+        };
+
+        Ok(Move::new(count, stack(from)?, stack(to)?))
+    }
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 5, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 5, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 5, 2, part_two);
+    }
+
+    #[test]
+    fn test_impossible_moves() {
+        let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\n";
+        let error = |m: &str| {
+            part_one(&format!("{}{}", input, m))
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            error("move 1 from 9 to 1"),
+            "line 7: could not parse \"move 1 from 9 to 1\": there is no stack 9, only 3"
+        );
+        assert_eq!(
+            error("move 5 from 1 to 2"),
+            "line 7: could not parse \"move 5 from 1 to 2\": stack 1 holds only 3 crates"
+        );
+    }
+}
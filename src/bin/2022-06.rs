@@ -1,27 +1,29 @@
 use std::collections::HashSet;
 
-pub fn part_one(input: &str) -> Option<u32> {
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
     let chars: Vec<_> = input.chars().collect();
 
     for (i, window) in chars.windows(4).enumerate() {
         if !has_duplicates(window) {
-            return Some((i + window.len()) as u32);
+            return Ok((i + window.len()) as u32);
         }
     }
 
-    None
+    Err(AocError::Unsolved)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, AocError> {
     let chars: Vec<_> = input.chars().collect();
 
     for (i, window) in chars.windows(14).enumerate() {
         if !has_duplicates(window) {
-            return Some((i + window.len()) as u32);
+            return Ok((i + window.len()) as u32);
         }
     }
 
-    None
+    Err(AocError::Unsolved)
 }
 
 fn has_duplicates(s: &[char]) -> bool {
@@ -39,9 +41,7 @@ fn has_duplicates(s: &[char]) -> bool {
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::solve!(2022, 6, part_one, part_two);
 }
 
 #[cfg(test)]
@@ -50,13 +50,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_one(&input), Some(7));
+        advent_of_code::examples::check(2022, 6, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
-        assert_eq!(part_two(&input), Some(19));
+        advent_of_code::examples::check(2022, 6, 2, part_two);
     }
 }
@@ -0,0 +1,152 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+use advent_of_code::parse::parse_lines;
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let root = Dir::parse(input)?;
+
+    Ok(Dir::all_dirs(Rc::clone(&root))
+        .filter(|dir| dir.borrow().size <= 100000)
+        .map(|dir| dir.borrow().size)
+        .sum::<u32>())
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let root = Dir::parse(input)?;
+
+    let used = root.borrow().size;
+    let unused = 70000000u32.checked_sub(used).ok_or_else(|| {
+        AocError::runtime(format!("{} bytes do not fit on a 70000000 byte disk", used))
+    })?;
+
+    Dir::all_dirs(Rc::clone(&root))
+        .map(|dir| dir.borrow().size)
+        .filter(|size| unused + size >= 30000000)
+        .min()
+        .ok_or(AocError::Unsolved)
+}
+
+#[derive(Debug)]
+struct Dir {
+    name: String,
+    size: u32,
+    parent: Option<Weak<RefCell<Dir>>>,
+    children: HashMap<String, Rc<RefCell<Dir>>>,
+}
+
+impl Dir {
+    fn new(name: &str) -> Self {
+        Dir {
+            name: name.to_string(),
+            size: 0,
+            parent: None,
+            children: HashMap::new(),
+        }
+    }
+
+    fn parse(input: &str) -> Result<Rc<RefCell<Dir>>, AocError> {
+        let root = Rc::new(RefCell::new(Dir::new("/")));
+        let mut cwd = Rc::clone(&root);
+
+        parse_lines(input, |line| {
+            let tokens: Vec<_> = line.splitn(3, ' ').collect();
+
+            match tokens[..] {
+                ["$", "cd", "/"] => cwd = Rc::clone(&root),
+                ["$", "cd", ".."] => {
+                    let next = cwd
+                        .borrow()
+                        .parent
+                        .as_ref()
+                        .and_then(Weak::upgrade)
+                        .ok_or_else(|| AocError::parse("", "the root has no parent directory"))?;
+                    cwd = next;
+                }
+                ["$", "cd", dirname] => cwd = Dir::add_child(Rc::clone(&cwd), Dir::new(dirname)),
+                ["$", "ls"] | ["dir", ..] => {}
+                [size, ..] if !size.starts_with('$') => {
+                    cwd.borrow_mut().size += size.parse::<u32>()?;
+                }
+                _ => return Err(AocError::parse("", "unknown command")),
+            }
+
+            Ok(())
+        })?;
+
+        Dir::compute_sizes(Rc::clone(&root));
+
+        Ok(root)
+    }
+
+    fn add_child(cwd: Rc<RefCell<Dir>>, mut child: Dir) -> Rc<RefCell<Dir>> {
+        child.parent = Some(Rc::downgrade(&cwd));
+        let name = child.name.clone();
+        let child = Rc::new(RefCell::new(child));
+        cwd.borrow_mut().children.insert(name, Rc::clone(&child));
+        child
+    }
+
+    fn compute_sizes(cwd: Rc<RefCell<Dir>>) {
+        let mut node = cwd.borrow_mut();
+        node.children
+            .iter()
+            .for_each(|(_, child)| Dir::compute_sizes(Rc::clone(child)));
+        node.size += node
+            .children
+            .values()
+            .map(|child| child.borrow().size)
+            .sum::<u32>();
+    }
+
+    fn all_dirs(cwd: Rc<RefCell<Dir>>) -> Box<dyn Iterator<Item = Rc<RefCell<Dir>>>> {
+        let children = cwd
+            .borrow()
+            .children
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        Box::new(
+            std::iter::once(cwd).chain(
+                children
+                    .into_iter()
+                    .flat_map(|child| Dir::all_dirs(child)),
+            ),
+        )
+    }
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 7, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 7, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 7, 2, part_two);
+    }
+    #[test]
+    fn test_invalid_output() {
+        let error = |input: &str| part_one(input).unwrap_err().to_string();
+
+        assert_eq!(
+            error("$ cd /\n$ cd .."),
+            "line 2: could not parse \"$ cd ..\": the root has no parent directory"
+        );
+        assert_eq!(
+            error("$ cd /\n$ rm a"),
+            "line 2: could not parse \"$ rm a\": unknown command"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::AocError;
+use take_until::TakeUntilExt;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let forest = Forest::try_from(input)?;
+    let count = forest.0.points().filter(|&p| forest.is_visible(p)).count();
+
+    Ok(count as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let forest = Forest::try_from(input)?;
+    let score = forest
+        .0
+        .points()
+        .map(|p| forest.score(p))
+        .max()
+        .unwrap_or(0);
+
+    Ok(score as u32)
+}
+
+#[derive(Debug)]
+struct Forest(Grid<u8>);
+
+impl TryFrom<&str> for Forest {
+    type Error = AocError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(s, |c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or_else(|| AocError::parse("", format!("'{}' is not a tree height", c)))
+        })?;
+
+        Ok(Forest(grid))
+    }
+}
+
+impl Forest {
+    fn is_visible(&self, p: Point) -> bool {
+        let h = self.0[p];
+
+        Point::ORTHOGONAL
+            .into_iter()
+            .any(|d| self.0.ray(p, d).all(|(_, &height)| height < h))
+    }
+
+    fn score(&self, p: Point) -> usize {
+        let h = self.0[p];
+
+        Point::ORTHOGONAL
+            .into_iter()
+            .map(|d| {
+                self.0
+                    .ray(p, d)
+                    .take_until(|&(_, &height)| height >= h)
+                    .count()
+            })
+            .product()
+    }
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 8, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 8, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 8, 2, part_two);
+    }
+}
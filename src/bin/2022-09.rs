@@ -1,33 +1,38 @@
 use std::collections::HashSet;
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let mut rope = Rope::new(2);
-    for line in input.lines() {
-        let tokens = line.split(" ").collect::<Vec<_>>();
-        let m = Motion::try_from(tokens[0]).unwrap();
-        let count = tokens[1].parse::<usize>().unwrap();
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::parse::parse_lines;
+use advent_of_code::visualize::{self, Cell, Frame, Rgb, Visualize};
+use advent_of_code::AocError;
 
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let mut rope = Rope::new(2);
+    for (m, count) in parse_lines(input, parse_step)? {
         for _ in 0..count {
             rope.step(&m);
         }
     }
 
-    Some(rope.visited.len() as u32)
+    Ok(rope.visited.len() as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, AocError> {
     let mut rope = Rope::new(10);
-    for line in input.lines() {
-        let tokens = line.split(" ").collect::<Vec<_>>();
-        let m = Motion::try_from(tokens[0]).unwrap();
-        let count = tokens[1].parse::<usize>().unwrap();
-
+    for (m, count) in parse_lines(input, parse_step)? {
         for _ in 0..count {
             rope.step(&m);
         }
     }
 
-    Some(rope.visited.len() as u32)
+    Ok(rope.visited.len() as u32)
+}
+
+fn parse_step(line: &str) -> Result<(Motion, usize), AocError> {
+    let (motion, count) = line
+        .split_once(' ')
+        .ok_or_else(|| AocError::parse(line, "expected a direction and a count"))?;
+
+    Ok((Motion::try_from(motion)?, count.parse::<usize>()?))
 }
 
 enum Motion {
@@ -38,14 +43,14 @@ enum Motion {
 }
 
 impl TryFrom<&str> for Motion {
-    type Error = &'static str;
+    type Error = AocError;
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
             "U" => Ok(Motion::Up),
             "D" => Ok(Motion::Down),
             "L" => Ok(Motion::Left),
             "R" => Ok(Motion::Right),
-            _ => Err("String cannot be parsed into Motion"),
+            _ => Err(AocError::parse(s, "expected one of U, D, L, R")),
         }
     }
 }
@@ -125,13 +130,49 @@ impl Rope {
         }
 
         self.visited.insert(self.knots.last().unwrap().clone());
+        visualize::emit(self);
+    }
+}
+
+impl Visualize for Rope {
+    // draws the visited positions and the knots, with y growing upwards like in the puzzle.
+    fn frame(&self) -> Frame {
+        let all = || self.visited.iter().chain(self.knots.iter());
+        let (min_x, max_x) = (
+            all().map(|p| p.x).min().unwrap(),
+            all().map(|p| p.x).max().unwrap(),
+        );
+        let (min_y, max_y) = (
+            all().map(|p| p.y).min().unwrap(),
+            all().map(|p| p.y).max().unwrap(),
+        );
+
+        let mut frame = Grid::new(
+            (max_x - min_x + 1) as usize,
+            (max_y - min_y + 1) as usize,
+            Cell::from('.'),
+        );
+        let at = |p: &Pos| Point::new((p.x - min_x) as isize, (max_y - p.y) as isize);
+
+        for p in &self.visited {
+            frame[at(p)] = Cell::from('#');
+        }
+        // the head is drawn last so it stays visible when knots overlap.
+        for (i, p) in self.knots.iter().enumerate().rev() {
+            let glyph = match i {
+                0 => 'H',
+                _ if self.knots.len() == 2 => 'T',
+                _ => char::from_digit(i as u32, 10).unwrap_or('+'),
+            };
+            frame[at(p)] = Cell::new(glyph, Rgb::RED);
+        }
+
+        frame
     }
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::solve!(2022, 9, part_one, part_two);
 }
 
 #[cfg(test)]
@@ -140,13 +181,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_one(&input), Some(88));
+        advent_of_code::examples::check(2022, 9, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
-        assert_eq!(part_two(&input), Some(36));
+        advent_of_code::examples::check(2022, 9, 2, part_two);
     }
 }
@@ -0,0 +1,465 @@
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::process;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use advent_of_code::helpers::bitmap::{self, Bitmap, Style};
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::ocr;
+use advent_of_code::parse::parse_lines;
+use advent_of_code::visualize::{self, Cell, Frame, Rgb, Visualize};
+use advent_of_code::{Answer, AocError};
+
+/// The screen part two draws on, set once by `--screen`, see [`Screen`].
+static SCREEN: OnceLock<Screen> = OnceLock::new();
+
+pub fn part_one(input: &str) -> Result<i32, AocError> {
+    let mut cpu = CPU::new(assemble(input)?);
+    cpu.breakpoints.extend((20..=220).step_by(40));
+
+    let mut strength = 0;
+    while let Some(tick) = cpu.run() {
+        strength += tick.x() * tick.cycle as i32;
+    }
+
+    Ok(strength)
+}
+
+pub fn part_two(input: &str) -> Result<Answer, AocError> {
+    let screen = SCREEN.get().copied().unwrap_or_default();
+
+    let mut cpu = CPU::new(assemble(input)?);
+    let mut crt = Crt::new(screen.width, screen.height, screen.sprite_width);
+
+    while let Some(tick) = cpu.step() {
+        crt.draw(&tick);
+    }
+
+    Ok(ocr::answer(bitmap::render(&crt.screen, Style::Text)))
+}
+
+/// The CRT to draw on, e.g. `80x12,5` instead of the puzzle's `40x6,3`, to try out larger programs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Screen {
+    width: usize,
+    height: usize,
+    sprite_width: usize,
+}
+
+impl Default for Screen {
+    fn default() -> Self {
+        Screen {
+            width: 40,
+            height: 6,
+            sprite_width: 3,
+        }
+    }
+}
+
+impl FromStr for Screen {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let usage = || format!("expected <width>x<height>,<sprite width>, found \"{}\"", s);
+        let number = |n: &str| n.trim().parse().ok().filter(|&n| n > 0).ok_or_else(usage);
+
+        let (size, sprite_width) = s.split_once(',').ok_or_else(usage)?;
+        let (width, height) = size.split_once('x').ok_or_else(usage)?;
+
+        Ok(Screen {
+            width: number(width)?,
+            height: number(height)?,
+            sprite_width: number(sprite_width)?,
+        })
+    }
+}
+
+/// The screen the CPU drives: it draws a pixel per cycle, left to right and top to bottom,
+/// and lights it if the sprite, `sprite_width` pixels centred on `x`, covers it.
+struct Crt {
+    screen: Bitmap,
+    sprite_width: usize,
+    /// The leftmost pixel of the sprite during the last cycle.
+    sprite: isize,
+    /// The pixel drawn during the last cycle.
+    beam: Point,
+}
+
+impl Crt {
+    fn new(width: usize, height: usize, sprite_width: usize) -> Self {
+        Crt {
+            screen: Bitmap::new(width, height, false),
+            sprite_width,
+            sprite: 0,
+            beam: Point::new(0, 0),
+        }
+    }
+
+    fn draw(&mut self, tick: &Tick) {
+        let size = self.screen.width() * self.screen.height();
+        self.beam = self.screen.point_of((tick.cycle - 1) % size);
+
+        self.sprite = tick.x() as isize - (self.sprite_width as isize - 1) / 2;
+        self.screen[self.beam] =
+            (self.sprite..self.sprite + self.sprite_width as isize).contains(&self.beam.x);
+        visualize::emit(self);
+    }
+}
+
+/// The registers of the CPU. `x` starts at 1, the others at 0.
+const REGISTERS: [char; 3] = ['x', 'y', 'z'];
+const X: usize = 0;
+
+type Registers = [i32; REGISTERS.len()];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operands {
+    None,
+    /// A register, appended to the mnemonic, and a value, e.g. `addx 3`.
+    RegisterValue,
+}
+
+/// An entry of the instruction set. An instruction takes effect at the end of its last cycle.
+struct Opcode {
+    mnemonic: &'static str,
+    operands: Operands,
+    cycles: usize,
+    exec: fn(&mut i32, i32),
+}
+
+const INSTRUCTION_SET: &[Opcode] = &[
+    Opcode {
+        mnemonic: "noop",
+        operands: Operands::None,
+        cycles: 1,
+        exec: |_, _| {},
+    },
+    Opcode {
+        mnemonic: "add",
+        operands: Operands::RegisterValue,
+        cycles: 2,
+        exec: |register, value| *register = register.wrapping_add(value),
+    },
+    Opcode {
+        mnemonic: "sub",
+        operands: Operands::RegisterValue,
+        cycles: 2,
+        exec: |register, value| *register = register.wrapping_sub(value),
+    },
+    Opcode {
+        mnemonic: "mul",
+        operands: Operands::RegisterValue,
+        cycles: 3,
+        exec: |register, value| *register = register.wrapping_mul(value),
+    },
+    Opcode {
+        mnemonic: "set",
+        operands: Operands::RegisterValue,
+        cycles: 1,
+        exec: |register, value| *register = value,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Instruction {
+    /// The index into [`INSTRUCTION_SET`].
+    opcode: usize,
+    register: usize,
+    value: i32,
+}
+
+impl Instruction {
+    fn opcode(&self) -> &'static Opcode {
+        &INSTRUCTION_SET[self.opcode]
+    }
+}
+
+/// Assembles a line like `addx -5`.
+impl TryFrom<&str> for Instruction {
+    type Error = AocError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (mnemonic, operand) = match s.split_once(' ') {
+            Some((mnemonic, operand)) => (mnemonic, Some(operand)),
+            None => (s, None),
+        };
+
+        let (opcode, register) = INSTRUCTION_SET
+            .iter()
+            .enumerate()
+            .find_map(|(i, opcode)| match opcode.operands {
+                Operands::None => (mnemonic == opcode.mnemonic).then_some((i, X)),
+                Operands::RegisterValue => {
+                    let mut suffix = mnemonic.strip_prefix(opcode.mnemonic)?.chars();
+                    let register = match (suffix.next(), suffix.next()) {
+                        (Some(c), None) => REGISTERS.iter().position(|&r| r == c),
+                        _ => None,
+                    }?;
+                    Some((i, register))
+                }
+            })
+            .ok_or_else(|| AocError::parse(s, format!("unknown instruction \"{}\"", mnemonic)))?;
+
+        let value = match (INSTRUCTION_SET[opcode].operands, operand) {
+            (Operands::None, None) => 0,
+            (Operands::RegisterValue, Some(value)) => value.parse()?,
+            (Operands::None, Some(_)) => {
+                return Err(AocError::parse(s, format!("{} takes no value", mnemonic)))
+            }
+            (Operands::RegisterValue, None) => {
+                return Err(AocError::parse(s, format!("{} needs a value", mnemonic)))
+            }
+        };
+
+        Ok(Instruction {
+            opcode,
+            register,
+            value,
+        })
+    }
+}
+
+/// Disassembles the instruction into the line it was assembled from.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let opcode = self.opcode();
+        match opcode.operands {
+            Operands::None => write!(f, "{}", opcode.mnemonic),
+            Operands::RegisterValue => write!(
+                f,
+                "{}{} {}",
+                opcode.mnemonic, REGISTERS[self.register], self.value
+            ),
+        }
+    }
+}
+
+fn assemble(source: &str) -> Result<Vec<Instruction>, AocError> {
+    parse_lines(source, Instruction::try_from)
+}
+
+fn disassemble(program: &[Instruction]) -> String {
+    program
+        .iter()
+        .map(|instruction| format!("{}\n", instruction))
+        .collect()
+}
+
+/// The state of the CPU during a cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Tick {
+    cycle: usize,
+    registers: Registers,
+}
+
+impl Tick {
+    fn x(&self) -> i32 {
+        self.registers[X]
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct CPU {
+    registers: Registers,
+    /// The running cycle, 0 before the first one.
+    cycle: usize,
+    program: Vec<Instruction>,
+    /// The index of the running instruction.
+    pc: usize,
+    /// The cycles left until the running instruction takes effect.
+    remaining: usize,
+    /// The cycles [`CPU::run`] stops at.
+    breakpoints: BTreeSet<usize>,
+}
+
+impl CPU {
+    fn new(program: Vec<Instruction>) -> Self {
+        advent_of_code::trace!("{}", disassemble(&program));
+
+        let mut registers = [0; REGISTERS.len()];
+        registers[X] = 1;
+
+        CPU {
+            registers,
+            cycle: 0,
+            program,
+            pc: 0,
+            remaining: 0,
+            breakpoints: BTreeSet::new(),
+        }
+    }
+
+    /// Runs a cycle and returns the state during it, or `None` once the program has ended.
+    fn step(&mut self) -> Option<Tick> {
+        let instruction = *self.program.get(self.pc)?;
+        let opcode = instruction.opcode();
+        if self.remaining == 0 {
+            self.remaining = opcode.cycles;
+        }
+
+        self.cycle += 1;
+        let tick = Tick {
+            cycle: self.cycle,
+            registers: self.registers,
+        };
+        advent_of_code::trace!(
+            "{:>5} | {:<10} | {}",
+            tick.cycle,
+            instruction.to_string(),
+            REGISTERS
+                .iter()
+                .zip(tick.registers)
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            (opcode.exec)(&mut self.registers[instruction.register], instruction.value);
+            self.pc += 1;
+        }
+
+        Some(tick)
+    }
+
+    /// Runs up to and including the next cycle with a breakpoint and returns the state during it,
+    /// or `None` if the program ends first.
+    fn run(&mut self) -> Option<Tick> {
+        while let Some(tick) = self.step() {
+            if self.breakpoints.contains(&tick.cycle) {
+                return Some(tick);
+            }
+        }
+        None
+    }
+}
+
+impl Visualize for Crt {
+    // draws the sprite above the screen, with the pixel the beam just drew in red.
+    fn frame(&self) -> Frame {
+        let mut frame = Grid::new(self.screen.width(), self.screen.height() + 1, Cell::EMPTY);
+
+        for x in self.sprite..self.sprite + self.sprite_width as isize {
+            if let Some(cell) = frame.get_mut(Point::new(x, 0)) {
+                *cell = Cell::from('#');
+            }
+        }
+        for (p, &lit) in self.screen.iter() {
+            frame[Point::new(p.x, p.y + 1)] = Cell::from(if lit { '#' } else { '.' });
+        }
+        let beam = Point::new(self.beam.x, self.beam.y + 1);
+        frame[beam] = Cell::new(frame[beam].glyph, Rgb::RED);
+
+        frame
+    }
+}
+
+fn main() {
+    match pico_args::Arguments::from_env().opt_value_from_str("--screen") {
+        Ok(Some(screen)) => SCREEN.set(screen).expect("the screen is only set here"),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    }
+
+    advent_of_code::solve!(2022, 10, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 10, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 10, 2, part_two);
+    }
+
+    #[test]
+    fn test_crt() {
+        // moves a 5 pixels wide sprite one pixel to the right every other cycle, for 32 cycles.
+        let program = "addx 1\n".repeat(16);
+        let mut cpu = CPU::new(assemble(&program).unwrap());
+        let mut crt = Crt::new(8, 4, 5);
+        while let Some(tick) = cpu.step() {
+            crt.draw(&tick);
+        }
+
+        let expected = "#######.\n.....###\n........\n........\n";
+        assert_eq!(bitmap::render(&crt.screen, Style::Text), expected);
+        assert_eq!(
+            bitmap::render(&crt.screen, Style::HalfBlocks),
+            "▀▀▀▀▀██▄\n        \n"
+        );
+
+        // the frames show the sprite above a screen of any size.
+        let frame = crt.frame();
+        assert_eq!((frame.width(), frame.height()), (8, 5));
+        assert_eq!(frame[Point::new(7, 4)], Cell::new('.', Rgb::RED));
+    }
+
+    #[test]
+    fn test_screen() {
+        assert_eq!(Screen::from_str("40x6,3"), Ok(Screen::default()));
+        assert_eq!(
+            Screen::from_str("80x12, 5"),
+            Ok(Screen {
+                width: 80,
+                height: 12,
+                sprite_width: 5,
+            })
+        );
+        assert!(Screen::from_str("40x0,3").is_err());
+        assert!(Screen::from_str("40x6").is_err());
+    }
+
+    #[test]
+    fn test_assemble() {
+        for example in advent_of_code::examples::load(2022, 10).unwrap() {
+            assert_eq!(
+                disassemble(&assemble(&example.input).unwrap()),
+                example.input
+            );
+        }
+
+        let program = assemble("setz 4\nmuly 3\nsubx -2").unwrap();
+        assert_eq!(disassemble(&program), "setz 4\nmuly 3\nsubx -2\n");
+
+        let error = |source| assemble(source).unwrap_err().to_string();
+        assert_eq!(
+            error("noop\njmp 3"),
+            "line 2: could not parse \"jmp 3\": unknown instruction \"jmp\""
+        );
+        assert_eq!(
+            error("addw 1"),
+            "line 1: could not parse \"addw 1\": unknown instruction \"addw\""
+        );
+        assert_eq!(
+            error("addx"),
+            "line 1: could not parse \"addx\": addx needs a value"
+        );
+        assert_eq!(
+            error("noop 1"),
+            "line 1: could not parse \"noop 1\": noop takes no value"
+        );
+    }
+
+    #[test]
+    fn test_cpu() {
+        let mut cpu = CPU::new(assemble("sety 3\nmuly -2\naddx 5\nnoop").unwrap());
+        cpu.breakpoints.extend([2, 6, 100]);
+
+        // `muly` takes effect at the end of cycle 4, `addx` at the end of cycle 6.
+        assert_eq!(cpu.run().map(|tick| tick.registers), Some([1, 3, 0]));
+        assert_eq!(cpu.run().map(|tick| tick.registers), Some([1, -6, 0]));
+        assert_eq!(cpu.run(), None);
+        assert_eq!((cpu.cycle, cpu.registers), (7, [6, -6, 0]));
+    }
+}
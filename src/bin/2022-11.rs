@@ -0,0 +1,912 @@
+use std::env;
+use std::fmt::{self, Debug, Display};
+use std::mem;
+use std::sync::Once;
+
+use advent_of_code::parse::{ints, ints_n, parse_blocks, Fields};
+use advent_of_code::AocError;
+use num_bigint::{BigInt, Sign};
+
+/// How many rounds of part two to also play with exact worry levels, to validate the modulo
+/// shortcut. Exact levels grow fast, so only a few rounds are feasible.
+const EXACT_ROUNDS: &str = "AOC_EXACT_ROUNDS";
+
+pub fn part_one(input: &str) -> Result<u64, AocError> {
+    let troop = Troop::try_from(input)?;
+    let counts = troop.play(20, true, |level| level)?;
+
+    Ok(monkey_business(&counts))
+}
+
+pub fn part_two(input: &str) -> Result<u64, AocError> {
+    let troop = Troop::try_from(input)?;
+
+    // the levels grow without bounds, only their remainders are needed to pick the next monkey.
+    if let Err(problem) = troop.check_modular() {
+        // once per run, not for every benchmark sample.
+        static WARNING: Once = Once::new();
+        WARNING.call_once(|| {
+            eprintln!(
+                "Warning: keeping worry levels modulo {} may change the outcome, {}",
+                troop.modulus, problem
+            )
+        });
+    }
+    if let Some(rounds) = exact_rounds()? {
+        troop.validate_modular(rounds)?;
+    }
+    let counts = troop.inspect_counts_after(10000)?;
+
+    Ok(monkey_business(&counts))
+}
+
+fn exact_rounds() -> Result<Option<usize>, AocError> {
+    match env::var(EXACT_ROUNDS) {
+        Ok(rounds) => rounds.parse().map(Some).map_err(|_| {
+            AocError::runtime(format!(
+                "{} must be a number of rounds, found \"{}\"",
+                EXACT_ROUNDS, rounds
+            ))
+        }),
+        Err(_) => Ok(None),
+    }
+}
+
+/// The product of the two highest inspect counts.
+fn monkey_business(counts: &[u64]) -> u64 {
+    let mut counts = counts.to_vec();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    counts.iter().take(2).product()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Operator {
+    fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '+' => Some(Operator::Add),
+            '-' => Some(Operator::Subtract),
+            '*' => Some(Operator::Multiply),
+            '/' => Some(Operator::Divide),
+            '%' => Some(Operator::Remainder),
+            _ => None,
+        }
+    }
+}
+
+/// The right-hand side of `new = ...`, e.g. `(old - 3) * old ^ 2 % 7`.
+/// Division and remainder round like `div_euclid` and `rem_euclid`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Old,
+    Literal(i64),
+    Negate(Box<Expr>),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+    /// Exponents are numbers, so that exact worry levels stay computable.
+    Power(Box<Expr>, u32),
+}
+
+impl Expr {
+    fn eval<W: Worry>(&self, old: &W) -> Result<W, String> {
+        match self {
+            Expr::Old => Ok(old.clone()),
+            Expr::Literal(n) => Ok(old.literal(*n)),
+            Expr::Negate(e) => old.literal(0).apply(Operator::Subtract, &e.eval(old)?),
+            Expr::Binary(op, lhs, rhs) => match (op, &**rhs) {
+                (Operator::Remainder, Expr::Literal(n)) => lhs.eval(old)?.remainder(*n),
+                _ => lhs.eval(old)?.apply(*op, &rhs.eval(old)?),
+            },
+            Expr::Power(base, exponent) => base.eval(old)?.pow(*exponent),
+        }
+    }
+
+    /// Why computing with the remainders of worry levels instead of the levels themselves
+    /// may give different remainders, if it may. Only `+`, `-`, `*` and `^` keep remainders
+    /// intact, and `%` by a positive number as long as the modulus is a multiple of it.
+    fn modular_problem(&self) -> Option<String> {
+        match self {
+            Expr::Old | Expr::Literal(_) => None,
+            Expr::Negate(e) | Expr::Power(e, _) => e.modular_problem(),
+            Expr::Binary(Operator::Divide, ..) => {
+                Some("dividing does not keep remainders intact".to_string())
+            }
+            Expr::Binary(Operator::Remainder, _, rhs) if !matches!(**rhs, Expr::Literal(n) if n > 0) => {
+                Some(
+                    "\"%\" by anything but a positive number does not keep remainders intact"
+                        .to_string(),
+                )
+            }
+            Expr::Binary(_, lhs, rhs) => lhs.modular_problem().or_else(|| rhs.modular_problem()),
+        }
+    }
+
+    /// The positive numbers that something is taken `%` of.
+    fn remainder_divisors(&self, divisors: &mut Vec<i64>) {
+        match self {
+            Expr::Old | Expr::Literal(_) => {}
+            Expr::Negate(e) | Expr::Power(e, _) => e.remainder_divisors(divisors),
+            Expr::Binary(op, lhs, rhs) => {
+                if let (Operator::Remainder, &Expr::Literal(n @ 1..)) = (op, &**rhs) {
+                    divisors.push(n);
+                }
+                lhs.remainder_divisors(divisors);
+                rhs.remainder_divisors(divisors);
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Expr {
+    type Error = AocError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+        };
+        let expr = parser.expr()?;
+
+        match parser.peek() {
+            Some(token) => Err(parser.unexpected(token)),
+            None => Ok(expr),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Number(&'a str),
+    Old,
+    Symbol(char),
+}
+
+impl Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Old => write!(f, "old"),
+            Token::Symbol(c) => write!(f, "{}", c),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token<'_>>, AocError> {
+    let mut tokens = vec![];
+    let mut rest = s.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        let (token, len) = if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            (Token::Number(&rest[..len]), len)
+        } else if rest.starts_with("old") {
+            (Token::Old, 3)
+        } else if "+-*/%^()".contains(c) {
+            (Token::Symbol(c), 1)
+        } else {
+            return Err(AocError::parse("", format!("unexpected \"{}\"", c)));
+        };
+
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+
+    Ok(tokens)
+}
+
+/// Parses an expression by recursive descent, from the loosest binding operators down:
+/// `+` and `-`, then `*`, `/` and `%`, then negation, then `^`.
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.pos += 1;
+        token
+    }
+
+    fn unexpected(&self, token: Token) -> AocError {
+        AocError::parse("", format!("unexpected \"{}\"", token))
+    }
+
+    /// Parses operands with `operand` for as long as they are joined by one of `symbols`.
+    fn binary(
+        &mut self,
+        symbols: &str,
+        mut operand: impl FnMut(&mut Self) -> Result<Expr, AocError>,
+    ) -> Result<Expr, AocError> {
+        let mut lhs = operand(self)?;
+
+        while let Some(Token::Symbol(c)) = self.peek() {
+            let Some(op) = Operator::from_symbol(c).filter(|_| symbols.contains(c)) else {
+                break;
+            };
+            self.pos += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(operand(self)?));
+        }
+
+        Ok(lhs)
+    }
+
+    fn expr(&mut self) -> Result<Expr, AocError> {
+        self.binary("+-", Parser::term)
+    }
+
+    fn term(&mut self) -> Result<Expr, AocError> {
+        self.binary("*/%", Parser::unary)
+    }
+
+    fn unary(&mut self) -> Result<Expr, AocError> {
+        if self.peek() == Some(Token::Symbol('-')) {
+            self.pos += 1;
+            return Ok(Expr::Negate(Box::new(self.unary()?)));
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Expr, AocError> {
+        let mut base = self.atom()?;
+
+        while self.peek() == Some(Token::Symbol('^')) {
+            self.pos += 1;
+            let exponent = match self.next() {
+                Some(Token::Number(n)) => n
+                    .parse()
+                    .map_err(|e| AocError::parse("", format!("exponent {}: {}", n, e)))?,
+                _ => return Err(AocError::parse("", "\"^\" needs a number as exponent")),
+            };
+            base = Expr::Power(Box::new(base), exponent);
+        }
+
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Expr, AocError> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Literal(n.parse()?)),
+            Some(Token::Old) => Ok(Expr::Old),
+            Some(Token::Symbol('(')) => {
+                let expr = self.expr()?;
+                match self.next() {
+                    Some(Token::Symbol(')')) => Ok(expr),
+                    _ => Err(AocError::parse("", "missing \")\"")),
+                }
+            }
+            Some(token) => Err(self.unexpected(token)),
+            None => Err(AocError::parse("", "expected a number, \"old\" or \"(\"")),
+        }
+    }
+}
+
+/// How worry levels are represented while the monkeys play.
+/// Errors are reasons, like `division by zero`.
+trait Worry: Clone + Debug {
+    /// `n` represented like `self`, e.g. modulo the same number.
+    fn literal(&self, n: i64) -> Self;
+    fn apply(&self, op: Operator, rhs: &Self) -> Result<Self, String>;
+    /// `self % n` for a number `n` as written, which [`Worry::literal`] may have reduced.
+    fn remainder(&self, n: i64) -> Result<Self, String> {
+        self.apply(Operator::Remainder, &self.literal(n))
+    }
+    fn pow(&self, exponent: u32) -> Result<Self, String>;
+    fn is_multiple_of(&self, divisor: u64) -> bool;
+}
+
+const OVERFLOW: &str = "the worry level overflowed";
+const DIVISION_BY_ZERO: &str = "division by zero";
+
+/// Exact worry levels, as long as they fit.
+impl Worry for i64 {
+    fn literal(&self, n: i64) -> Self {
+        n
+    }
+
+    fn apply(&self, op: Operator, rhs: &Self) -> Result<Self, String> {
+        if matches!(op, Operator::Divide | Operator::Remainder) && *rhs == 0 {
+            return Err(DIVISION_BY_ZERO.to_string());
+        }
+
+        match op {
+            Operator::Add => self.checked_add(*rhs),
+            Operator::Subtract => self.checked_sub(*rhs),
+            Operator::Multiply => self.checked_mul(*rhs),
+            Operator::Divide => self.checked_div_euclid(*rhs),
+            Operator::Remainder => self.checked_rem_euclid(*rhs),
+        }
+        .ok_or_else(|| OVERFLOW.to_string())
+    }
+
+    fn pow(&self, exponent: u32) -> Result<Self, String> {
+        self.checked_pow(exponent)
+            .ok_or_else(|| OVERFLOW.to_string())
+    }
+
+    fn is_multiple_of(&self, divisor: u64) -> bool {
+        self.unsigned_abs().is_multiple_of(divisor)
+    }
+}
+
+/// Exact worry levels of any size, to check the results of [`Modular`] against.
+impl Worry for BigInt {
+    fn literal(&self, n: i64) -> Self {
+        BigInt::from(n)
+    }
+
+    fn apply(&self, op: Operator, rhs: &Self) -> Result<Self, String> {
+        // `/` and `%` truncate, while worry levels divide with the Euclidean definitions.
+        let divided = || {
+            if rhs.sign() == Sign::NoSign {
+                return Err(DIVISION_BY_ZERO.to_string());
+            }
+
+            let (mut quotient, mut remainder) = (self / rhs, self % rhs);
+            if remainder.sign() == Sign::Minus {
+                if rhs.sign() == Sign::Plus {
+                    quotient -= 1;
+                    remainder += rhs;
+                } else {
+                    quotient += 1;
+                    remainder -= rhs;
+                }
+            }
+            Ok((quotient, remainder))
+        };
+
+        match op {
+            Operator::Add => Ok(self + rhs),
+            Operator::Subtract => Ok(self - rhs),
+            Operator::Multiply => Ok(self * rhs),
+            Operator::Divide => divided().map(|(quotient, _)| quotient),
+            Operator::Remainder => divided().map(|(_, remainder)| remainder),
+        }
+    }
+
+    fn pow(&self, exponent: u32) -> Result<Self, String> {
+        Ok(BigInt::pow(self, exponent))
+    }
+
+    fn is_multiple_of(&self, divisor: u64) -> bool {
+        match divisor {
+            0 => self.sign() == Sign::NoSign,
+            _ => (self % divisor).sign() == Sign::NoSign,
+        }
+    }
+}
+
+/// The remainder of a worry level modulo a multiple of every divisor the monkeys test for.
+/// Which monkey an item goes to only depends on it, see [`Expr::modular_problem`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Modular {
+    value: i64,
+    modulus: i64,
+}
+
+impl Modular {
+    fn new(value: i64, modulus: i64) -> Self {
+        Modular {
+            value: value.rem_euclid(modulus),
+            modulus,
+        }
+    }
+
+    fn wide(&self, value: i128) -> Self {
+        Modular {
+            value: value.rem_euclid(self.modulus as i128) as i64,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Worry for Modular {
+    fn literal(&self, n: i64) -> Self {
+        Modular::new(n, self.modulus)
+    }
+
+    fn apply(&self, op: Operator, rhs: &Self) -> Result<Self, String> {
+        let (a, b) = (self.value as i128, rhs.value as i128);
+        if matches!(op, Operator::Divide | Operator::Remainder) && b == 0 {
+            return Err(DIVISION_BY_ZERO.to_string());
+        }
+
+        Ok(self.wide(match op {
+            Operator::Add => a + b,
+            Operator::Subtract => a - b,
+            Operator::Multiply => a * b,
+            Operator::Divide => a.div_euclid(b),
+            Operator::Remainder => a.rem_euclid(b),
+        }))
+    }
+
+    // a number equal to the modulus is 0 as a literal, but the remainder by it is still known.
+    fn remainder(&self, n: i64) -> Result<Self, String> {
+        if n == 0 {
+            return Err(DIVISION_BY_ZERO.to_string());
+        }
+        Ok(self.wide((self.value as i128).rem_euclid(n as i128)))
+    }
+
+    fn pow(&self, mut exponent: u32) -> Result<Self, String> {
+        let mut base = *self;
+        let mut result = self.literal(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.apply(Operator::Multiply, &base)?;
+            }
+            base = base.apply(Operator::Multiply, &base)?;
+            exponent >>= 1;
+        }
+        Ok(result)
+    }
+
+    fn is_multiple_of(&self, divisor: u64) -> bool {
+        (self.value as u64).is_multiple_of(divisor)
+    }
+}
+
+#[derive(Debug)]
+struct Test {
+    divisor: u64,
+    on_true: usize,
+    on_false: usize,
+}
+
+impl Test {
+    fn new(divisor: u64, on_true: usize, on_false: usize) -> Self {
+        Test {
+            divisor,
+            on_true,
+            on_false,
+        }
+    }
+
+    fn find_next(&self, worry_level: &impl Worry) -> usize {
+        if worry_level.is_multiple_of(self.divisor) {
+            self.on_true
+        } else {
+            self.on_false
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: Vec<i64>,
+    operation: Expr,
+    test: Test,
+}
+
+impl TryFrom<&str> for Monkey {
+    type Error = AocError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let fields = Fields::parse(s)?;
+
+        let items = fields.parse_with("Starting items", ints)?;
+        let operation = fields.parse_with("Operation", |op| {
+            op.strip_prefix("new = ")
+                .ok_or_else(|| AocError::parse("", "expected \"new = <expression>\""))
+                .and_then(Expr::try_from)
+        })?;
+        let [divisor] = fields.parse_with("Test", |test| match ints_n(test)? {
+            [0] => Err(AocError::parse("", "cannot test for divisibility by 0")),
+            divisor => Ok(divisor),
+        })?;
+        let [on_true] = fields.parse_with("If true", ints_n)?;
+        let [on_false] = fields.parse_with("If false", ints_n)?;
+        let test = Test::new(divisor, on_true, on_false);
+
+        Ok(Monkey {
+            items,
+            operation,
+            test,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Troop {
+    monkeys: Vec<Monkey>,
+    /// The least common multiple of the divisors the monkeys test for or take `%` of.
+    modulus: i64,
+}
+
+impl Troop {
+    /// Why keeping worry levels modulo [`Troop::modulus`] may change the outcome, if it may.
+    fn check_modular(&self) -> Result<(), String> {
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            if let Some(problem) = monkey.operation.modular_problem() {
+                return Err(format!("monkey {}: {}", i, problem));
+            }
+        }
+        Ok(())
+    }
+
+    /// Plays `rounds` rounds with worry levels represented by `worry` and returns
+    /// how many items every monkey inspected.
+    fn play<W: Worry>(
+        &self,
+        rounds: usize,
+        divide_by_three: bool,
+        worry: impl Fn(i64) -> W,
+    ) -> Result<Vec<u64>, AocError> {
+        let mut items: Vec<Vec<W>> = self
+            .monkeys
+            .iter()
+            .map(|monkey| monkey.items.iter().map(|&level| worry(level)).collect())
+            .collect();
+        let mut counts = vec![0; self.monkeys.len()];
+
+        for round in 1..=rounds {
+            for (i, monkey) in self.monkeys.iter().enumerate() {
+                let held = mem::take(&mut items[i]);
+                counts[i] += held.len() as u64;
+
+                for item in held {
+                    let level = monkey
+                        .operation
+                        .eval(&item)
+                        .and_then(|level| {
+                            if divide_by_three {
+                                level.apply(Operator::Divide, &level.literal(3))
+                            } else {
+                                Ok(level)
+                            }
+                        })
+                        .map_err(|reason| {
+                            AocError::runtime(format!("round {}, monkey {}: {}", round, i, reason))
+                        })?;
+                    items[monkey.test.find_next(&level)].push(level);
+                }
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Plays `rounds` rounds without relief with exact worry levels and fails if keeping
+    /// the levels modulo [`Troop::modulus`] gives different inspect counts.
+    fn validate_modular(&self, rounds: usize) -> Result<(), AocError> {
+        let exact = self.play(rounds, false, BigInt::from)?;
+        let modular = self.inspect_counts_after(rounds as u64)?;
+        if modular != exact {
+            return Err(AocError::runtime(format!(
+                    "keeping worry levels modulo {} gives the inspect counts {:?} after {} rounds instead of {:?}",
+                    self.modulus, modular, rounds, exact
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// How many items every monkey inspected after `rounds` rounds without relief,
+    /// with worry levels kept modulo [`Troop::modulus`].
+    ///
+    /// Without relief an item travels the same way no matter what the other items do, and with
+    /// its level kept modulo the troop's modulus it can only be in so many places at the start
+    /// of a round. So every item eventually repeats a cycle of rounds, which is counted once
+    /// and multiplied instead of played over and over, making e.g. 10^12 rounds as quick as 10^4.
+    fn inspect_counts_after(&self, rounds: u64) -> Result<Vec<u64>, AocError> {
+        let mut counts = vec![0; self.monkeys.len()];
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            for &level in &monkey.items {
+                self.count_item((i, Modular::new(level, self.modulus)), rounds, &mut counts)?;
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Adds the inspections of the item in `start` over `rounds` rounds to `counts`.
+    fn count_item(
+        &self,
+        start: ItemState,
+        rounds: u64,
+        counts: &mut [u64],
+    ) -> Result<(), AocError> {
+        let mut ignored = vec![0; counts.len()];
+
+        // Brent's algorithm finds the length of the cycle, unless the rounds run out first.
+        let (mut power, mut length) = (1, 1);
+        let mut tortoise = start;
+        let mut hare = self.item_round(start, &mut ignored)?;
+        let mut played = 1;
+        while tortoise != hare {
+            if played >= rounds {
+                return self.advance(start, rounds, counts).map(|_| ());
+            }
+            if power == length {
+                tortoise = hare;
+                power *= 2;
+                length = 0;
+            }
+            hare = self.item_round(hare, &mut ignored)?;
+            length += 1;
+            played += 1;
+        }
+
+        // the cycle starts where two states `length` rounds apart first meet.
+        let mut tortoise = start;
+        let mut hare = self.advance(start, length, &mut ignored)?;
+        let mut before = 0;
+        while tortoise != hare {
+            tortoise = self.item_round(tortoise, &mut ignored)?;
+            hare = self.item_round(hare, &mut ignored)?;
+            before += 1;
+        }
+
+        if rounds <= before + length {
+            return self.advance(start, rounds, counts).map(|_| ());
+        }
+        let state = self.advance(start, before, counts)?;
+        let mut cycle = vec![0; counts.len()];
+        self.advance(state, length, &mut cycle)?;
+        let repeats = (rounds - before) / length;
+        for (count, per_cycle) in counts.iter_mut().zip(&cycle) {
+            *count += per_cycle * repeats;
+        }
+        self.advance(state, (rounds - before) % length, counts)?;
+
+        Ok(())
+    }
+
+    /// Plays `rounds` rounds for a single item, see [`Troop::item_round`].
+    fn advance(
+        &self,
+        mut state: ItemState,
+        rounds: u64,
+        counts: &mut [u64],
+    ) -> Result<ItemState, AocError> {
+        for _ in 0..rounds {
+            state = self.item_round(state, counts)?;
+        }
+        Ok(state)
+    }
+
+    /// Plays a round for a single item, adding its inspections to `counts`,
+    /// and returns where it is at the start of the next round.
+    fn item_round(
+        &self,
+        (mut i, mut level): ItemState,
+        counts: &mut [u64],
+    ) -> Result<ItemState, AocError> {
+        loop {
+            counts[i] += 1;
+            let monkey = &self.monkeys[i];
+            level = monkey
+                .operation
+                .eval(&level)
+                .map_err(|reason| AocError::runtime(format!("monkey {}: {}", i, reason)))?;
+
+            // the monkeys take turns in order, so one before this monkey gets the item next round.
+            let next = monkey.test.find_next(&level);
+            if next < i {
+                return Ok((next, level));
+            }
+            i = next;
+        }
+    }
+}
+
+/// The monkey holding an item at the start of a round and the item's worry level.
+type ItemState = (usize, Modular);
+
+fn lcm(a: i64, b: i64) -> Option<i64> {
+    let gcd = |mut a: i64, mut b: i64| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        a
+    };
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+impl TryFrom<&str> for Troop {
+    type Error = AocError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let monkeys = parse_blocks(s, Monkey::try_from)?;
+
+        let mut divisors = vec![];
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for target in [monkey.test.on_true, monkey.test.on_false] {
+                if target == i || target >= monkeys.len() {
+                    return Err(AocError::parse(
+                        "",
+                        format!("monkey {} cannot throw to monkey {}", i, target),
+                    ));
+                }
+            }
+            divisors.push(monkey.test.divisor as i64);
+            monkey.operation.remainder_divisors(&mut divisors);
+        }
+
+        let modulus = divisors
+            .into_iter()
+            .filter(|&divisor| divisor > 0)
+            .try_fold(1, lcm)
+            .ok_or_else(|| AocError::parse("", "the divisors have no common multiple that fits"))?;
+
+        Ok(Troop { monkeys, modulus })
+    }
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 11, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        advent_of_code::examples::load(2022, 11).unwrap()[0]
+            .input
+            .clone()
+    }
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 11, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 11, 2, part_two);
+    }
+
+    #[test]
+    fn test_expr() {
+        let eval = |s: &str, old: i64| Expr::try_from(s).unwrap().eval(&old);
+
+        assert_eq!(eval("old * 19", 2), Ok(38));
+        assert_eq!(eval("2 + old * 3 ^ 2", 2), Ok(20));
+        assert_eq!(eval("(old - 10) / 4 % 3", 2), Ok(1));
+        assert_eq!(eval("-old ^ 2 - -1", 3), Ok(-8));
+        assert_eq!(
+            eval("old / (old - 2)", 2),
+            Err(DIVISION_BY_ZERO.to_string())
+        );
+        assert_eq!(eval("old ^ 63", 2), Err(OVERFLOW.to_string()));
+
+        // exact levels of any size divide like i64 does.
+        for s in [
+            "old / 4",
+            "old % 4",
+            "old / (0 - 4)",
+            "old % (0 - 4)",
+            "old / (old - 2)",
+        ] {
+            let expr = Expr::try_from(s).unwrap();
+            for old in [-7, 7] {
+                let exact = expr.eval(&BigInt::from(old));
+                assert_eq!(
+                    exact,
+                    expr.eval(&old).map(BigInt::from),
+                    "{} with {}",
+                    s,
+                    old
+                );
+            }
+        }
+
+        let error = |s: &str| Expr::try_from(s).unwrap_err().to_string();
+        assert_eq!(
+            error("old +"),
+            "could not parse the input: expected a number, \"old\" or \"(\""
+        );
+        assert_eq!(
+            error("(old * 2"),
+            "could not parse the input: missing \")\""
+        );
+        assert_eq!(
+            error("old 2"),
+            "could not parse the input: unexpected \"2\""
+        );
+        assert_eq!(
+            error("old & 2"),
+            "could not parse the input: unexpected \"&\""
+        );
+        assert_eq!(
+            error("old ^ old"),
+            "could not parse the input: \"^\" needs a number as exponent"
+        );
+    }
+
+    #[test]
+    fn test_modular_shortcut() {
+        let troop = Troop::try_from(example().as_str()).unwrap();
+        assert_eq!(troop.modulus, 23 * 19 * 13 * 17);
+        assert_eq!(troop.check_modular(), Ok(()));
+
+        // exact levels square in size with every visit to monkey 2, so only a few rounds are feasible.
+        let exact = troop.play(10, false, BigInt::from).unwrap();
+        let modular = troop
+            .play(10, false, |level| Modular::new(level, troop.modulus))
+            .unwrap();
+        assert_eq!(modular, exact);
+        assert_eq!(troop.validate_modular(10), Ok(()));
+    }
+
+    #[test]
+    fn test_check_modular() {
+        let input = example().replace("new = old + 6", "new = (old + 6) % 10");
+        let troop = Troop::try_from(input.as_str()).unwrap();
+        assert_eq!(troop.modulus, 23 * 19 * 13 * 17 * 10);
+        assert_eq!(troop.check_modular(), Ok(()));
+
+        let input = example().replace("new = old + 6", "new = old * 7 / 3");
+        let troop = Troop::try_from(input.as_str()).unwrap();
+        assert_eq!(
+            troop.check_modular(),
+            Err("monkey 1: dividing does not keep remainders intact".to_string())
+        );
+
+        let exact = troop.play(5, false, BigInt::from).unwrap();
+        let modular = troop
+            .play(5, false, |level| Modular::new(level, troop.modulus))
+            .unwrap();
+        assert_ne!(modular, exact);
+        assert!(troop.validate_modular(5).is_err());
+
+        let input = example().replace("new = old + 6", "new = old % 0");
+        let troop = Troop::try_from(input.as_str()).unwrap();
+        assert_eq!(
+            troop.check_modular(),
+            Err(
+                "monkey 1: \"%\" by anything but a positive number does not keep remainders intact"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_remainder_by_modulus() {
+        // with every test by 7, the modulus is 7 itself.
+        let input = ["23", "19", "13", "17"]
+            .iter()
+            .fold(example(), |input, divisor| {
+                input.replace(&format!("divisible by {}", divisor), "divisible by 7")
+            })
+            .replace("new = old * 19", "new = old * 19 % 7");
+        let troop = Troop::try_from(input.as_str()).unwrap();
+        assert_eq!(troop.modulus, 7);
+        assert_eq!(troop.check_modular(), Ok(()));
+
+        let exact = troop.play(10, false, BigInt::from).unwrap();
+        let modular = troop
+            .play(10, false, |level| Modular::new(level, troop.modulus))
+            .unwrap();
+        assert_eq!(modular, exact);
+    }
+
+    #[test]
+    fn test_inspect_counts_after() {
+        let troop = Troop::try_from(example().as_str()).unwrap();
+
+        for rounds in [0, 1, 20, 1000, 10000] {
+            let played = troop
+                .play(rounds, false, |level| Modular::new(level, troop.modulus))
+                .unwrap();
+            assert_eq!(troop.inspect_counts_after(rounds as u64).unwrap(), played);
+        }
+        assert_eq!(
+            troop.inspect_counts_after(10000).unwrap(),
+            [52166, 47830, 1938, 52013]
+        );
+
+        // every item is inspected at least once a round.
+        let counts = troop.inspect_counts_after(1_000_000_000_000).unwrap();
+        assert!(counts.iter().sum::<u64>() >= 10 * 1_000_000_000_000);
+    }
+}
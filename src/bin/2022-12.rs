@@ -0,0 +1,114 @@
+use advent_of_code::helpers::graph::bfs;
+use advent_of_code::helpers::{Grid, Point};
+use advent_of_code::AocError;
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let h = Heightmap::try_from(input)?;
+    let start = h.find(SquareKind::Start)?;
+    let end = h.find(SquareKind::End)?;
+
+    bfs([start], |&p| h.steps_from(p), |&p| p == end)
+        .map(|path| path.steps() as u32)
+        .ok_or(AocError::Unsolved)
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let h = Heightmap::try_from(input)?;
+    let end = h.find(SquareKind::End)?;
+    let starts = h
+        .grid
+        .iter()
+        .filter(|(_, s)| s.elevation == 0)
+        .map(|(p, _)| p);
+
+    bfs(starts, |&p| h.steps_from(p), |&p| p == end)
+        .map(|path| path.steps() as u32)
+        .ok_or(AocError::Unsolved)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SquareKind {
+    Start,
+    End,
+    Ordinary,
+}
+type ElevationType = u32;
+
+#[derive(Debug, Clone)]
+struct Square {
+    elevation: ElevationType,
+    kind: SquareKind,
+}
+
+impl Square {
+    fn new(elevation: ElevationType, kind: SquareKind) -> Self {
+        Square { elevation, kind }
+    }
+
+    fn can_step_to(&self, other: &Square) -> bool {
+        self.elevation + 1 >= other.elevation
+    }
+}
+
+#[derive(Debug)]
+struct Heightmap {
+    grid: Grid<Square>,
+}
+
+impl TryFrom<&str> for Heightmap {
+    type Error = AocError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(s, |c| match c {
+            'S' => Ok(Square::new(0, SquareKind::Start)),
+            'E' => Ok(Square::new(
+                'z' as ElevationType - 'a' as ElevationType,
+ /* ..ahhh.......... */ SquareKind::End,
+            )),
+            'a'..='z' => Ok(Square::new(
+                c as ElevationType - 'a' as ElevationType,
+                SquareKind::Ordinary,
+            )),
+            _ => Err(AocError::parse("", format!("invalid square '{}'", c))),
+        })?;
+
+        Ok(Heightmap { grid })
+    }
+}
+
+impl Heightmap {
+    fn find(&self, kind: SquareKind) -> Result<Point, AocError> {
+        self.grid.position(|s| s.kind == kind).ok_or_else(|| {
+            let square = match kind {
+                SquareKind::Start => "start square S",
+                SquareKind::End => "end square E",
+                SquareKind::Ordinary => "ordinary square",
+            };
+            AocError::parse("", format!("the heightmap has no {}", square))
+        })
+    }
+
+    fn steps_from(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.grid
+            .neighbours4(p)
+            .filter(move |&q| self.grid[p].can_step_to(&self.grid[q]))
+    }
+}
+
+fn main() {
+    advent_of_code::solve!(2022, 12, part_one, part_two);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        advent_of_code::examples::check(2022, 12, 1, part_one);
+    }
+
+    #[test]
+    fn test_part_two() {
+        advent_of_code::examples::check(2022, 12, 2, part_two);
+    }
+}
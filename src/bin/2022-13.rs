@@ -1,38 +1,38 @@
 use std::cmp::Ordering;
 
+use advent_of_code::parse::{parse_blocks, parse_lines};
+use advent_of_code::AocError;
 use serde::{Deserialize, Serialize};
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let pairs = input
-        .split("\n\n")
-        .map(|pair| {
-            pair.lines()
-                .map(|line| serde_json::from_str::<'_, Packet>(line).unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let pairs = parse_blocks(input, |block| {
+        let [l, r]: [Packet; 2] = parse_lines(block, parse_packet)?
+            .try_into()
+            .map_err(|_| AocError::parse("", "expected a pair of packets"))?;
+        Ok((l, r))
+    })?;
 
     let mut sum = 0;
-    for (i, pair) in pairs.iter().enumerate() {
-        let l = &pair[0];
-        let r = &pair[1];
+    for (i, (l, r)) in pairs.iter().enumerate() {
         if l <= r {
             sum += i + 1;
         }
     }
 
-    Some(sum as u32)
+    Ok(sum as u32)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let mut packets = input
-        .split("\n\n")
-        .map(|pair| {
-            pair.lines()
-                .map(|line| serde_json::from_str::<'_, Packet>(line).unwrap())
-        })
-        .flatten()
-        .collect::<Vec<_>>();
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let mut packets = parse_lines(input, |line| {
+        if line.is_empty() {
+            Ok(None)
+        } else {
+            parse_packet(line).map(Some)
+        }
+    })?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
 
     let dividers = (
         Packet::List(vec![Packet::List(vec![Packet::Number(2)])]),
@@ -53,7 +53,11 @@ pub fn part_two(input: &str) -> Option<u32> {
         .position(|packet| packet == &dividers.1)
         .unwrap();
 
-    Some((i + 1) as u32 * (j + 1) as u32)
+    Ok((i + 1) as u32 * (j + 1) as u32)
+}
+
+fn parse_packet(line: &str) -> Result<Packet, AocError> {
+    serde_json::from_str(line).map_err(|e| AocError::parse("", e))
 }
 
 #[derive(Deserialize, Serialize, PartialEq, Eq, Clone)]
@@ -74,11 +78,11 @@ impl Packet {
 
 // credit to https://fasterthanli.me/series/advent-of-code-2022/part-13
 // this solution was too elegant not to take
-impl std::cmp::PartialOrd for Packet {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl std::cmp::Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
-            (Packet::Number(a), Packet::Number(b)) => a.partial_cmp(b),
-            (l, r) => Some(l.with_slice(|l| {
+            (Packet::Number(a), Packet::Number(b)) => a.cmp(b),
+            (l, r) => l.with_slice(|l| {
                 r.with_slice(|r| {
                     l.iter()
                         .zip(r.iter())
@@ -88,21 +92,19 @@ impl std::cmp::PartialOrd for Packet {
                         // or compare the lengths
                         .unwrap_or_else(|| l.len().cmp(&r.len()))
                 })
-            })),
+            }),
         }
     }
 }
 
-impl std::cmp::Ord for Packet {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+impl std::cmp::PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::solve!(2022, 13, part_one, part_two);
 }
 
 #[cfg(test)]
@@ -111,13 +113,11 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
+        advent_of_code::examples::check(2022, 13, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
+        advent_of_code::examples::check(2022, 13, 2, part_two);
     }
 }
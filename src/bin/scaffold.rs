@@ -3,23 +3,27 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+use advent_of_code::aoc::{self, Client};
+use advent_of_code::{examples, input, puzzle};
+
+const MODULE_TEMPLATE: &str = r###"use advent_of_code::{Answer, AocError};
+
+pub fn part_one(input: &str) -> Result<Answer, AocError> {
+    Err(AocError::Unsolved)
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+pub fn part_two(input: &str) -> Result<Answer, AocError> {
+    Err(AocError::Unsolved)
 }
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    advent_of_code::solve!(YEAR, DAY, part_one, part_two);
 }
 
 #[cfg(test)]
@@ -28,87 +32,233 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+        advent_of_code::examples::check(YEAR, DAY, 1, part_one);
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+        advent_of_code::examples::check(YEAR, DAY, 2, part_two);
     }
 }
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+const EXPECTED_TEMPLATE: &str = r###"# The expected answers of the examples in this directory, one table per example file:
+#
+# [example]
+# part_one = 42
+# part_two = "abc"
+#
+# The tests of a part fail until an example has an expected answer for it.
+"###;
+
+struct Args {
+    day: u8,
+    year: i16,
+    /// Fetch the puzzle page from the website.
+    puzzle: bool,
+    /// Read a saved copy of the puzzle page instead of fetching it.
+    puzzle_file: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    Ok(Args {
+        puzzle: args.contains("--puzzle"),
+        puzzle_file: args.opt_value_from_str("--puzzle-file")?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(advent_of_code::DEFAULT_YEAR),
+        day: args.free_from_str()?,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+fn read_puzzle_html(args: &Args) -> Result<Option<String>, String> {
+    if let Some(path) = &args.puzzle_file {
+        return fs::read_to_string(path)
+            .map(Some)
+            .map_err(|e| format!("Failed to read puzzle file \"{}\": {}", path, e));
+    }
+
+    if !args.puzzle {
+        return Ok(None);
+    }
+
+    let year = args.year;
+    let client = Client::new(aoc::read_session()?);
+
+    println!(
+        "Fetching puzzle from {}...",
+        client.puzzle_url(year, args.day)
+    );
+
+    client
+        .puzzle(year, args.day)
+        .map(Some)
+        .map_err(|e| format!("Failed to fetch puzzle: {}", e))
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let (year, day) = (args.year, args.day);
+    let bin_name = format!("{}-{:02}", year, day);
+
+    let input_path = input::path(year, "inputs", day, "txt");
+    let example_path = examples::dir(year, day).join("example.txt");
+    let expected_path = examples::dir(year, day).join(examples::EXPECTED_FILE);
+    let module_path = input::root_dir()
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", bin_name));
+    let puzzle_path = input::path(year, "puzzles", day, "md");
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let with_puzzle = args.puzzle || args.puzzle_file.is_some();
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    match safe_create_file(&module_path) {
+        Ok(mut file) => {
+            let module = MODULE_TEMPLATE
+                .replace("YEAR", &year.to_string())
+                .replace("DAY", &day.to_string());
+
+            match file.write_all(module.as_bytes()) {
+                Ok(_) => {
+                    println!("Created module file \"{}\"", module_path.display());
+                }
+                Err(e) => {
+                    eprintln!("Failed to write module contents: {}", e);
+                    process::exit(1);
+                }
+            }
+        }
+        // allows fetching the puzzle again once part two is unlocked.
+        Err(e) if with_puzzle && e.kind() == std::io::ErrorKind::AlreadyExists => {
+            println!(
+                "Module file \"{}\" already exists, skipping",
+                module_path.display()
+            );
+        }
         Err(e) => {
             eprintln!("Failed to create module file: {}", e);
             process::exit(1);
         }
-    };
+    }
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match create_file(&input_path) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to create input file: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
+    match create_file(&example_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty example file \"{}\"", example_path.display());
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to create example file: {}", e);
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
+    match create_file(&expected_path).and_then(|mut file| {
+        // keeps the expected answers that were already filled in.
+        if file.metadata()?.len() == 0 {
+            file.write_all(EXPECTED_TEMPLATE.as_bytes())?;
+        }
+        Ok(())
+    }) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!(
+                "Created expected answers file \"{}\"",
+                expected_path.display()
+            );
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+            eprintln!("Failed to create expected answers file: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let html = match read_puzzle_html(&args) {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("{}", e);
             process::exit(1);
         }
+    };
+
+    if let Some(html) = html {
+        let puzzle = puzzle::parse(&html);
+
+        match create_file(&puzzle_path).and_then(|_| fs::write(&puzzle_path, &puzzle.markdown)) {
+            Ok(_) => {
+                println!("Wrote puzzle description to \"{}\"", puzzle_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to write puzzle description: {}", e);
+                process::exit(1);
+            }
+        }
+
+        // never overwrite an example that was already filled in.
+        let example_is_empty = fs::metadata(&example_path).map_or(true, |m| m.len() == 0);
+
+        match puzzle.example {
+            Some(example) if example_is_empty => match fs::write(&example_path, example) {
+                Ok(_) => {
+                    println!(
+                        "Filled example file \"{}\" from the puzzle",
+                        example_path.display()
+                    );
+                }
+                Err(e) => {
+                    eprintln!("Failed to write example file: {}", e);
+                    process::exit(1);
+                }
+            },
+            Some(_) => {}
+            None => {
+                println!("No example found in the puzzle description");
+            }
+        }
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    println!("🎄 Type `cargo solve {}` to run your solution.", &bin_name);
+
+    // days are registered by hand, so that a day in progress cannot break `cargo all`.
+    let registry_path = input::root_dir().join("src").join("days.rs");
+    let registration = format!("({}, {}) => \"bin/{}.rs\",", year, day, bin_name);
+    if !fs::read_to_string(&registry_path).is_ok_and(|registry| registry.contains(&registration)) {
+        println!(
+            "🎄 Once it compiles, add `{}` to \"{}\" to run it with `cargo all`.",
+            registration,
+            registry_path.display()
+        );
+    }
 }
@@ -0,0 +1,273 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::{self, Write};
+use std::process::{self, Command};
+
+use advent_of_code::answers::Answers;
+use advent_of_code::runner;
+use advent_of_code::Answer;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: i16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(advent_of_code::DEFAULT_YEAR),
+    })
+}
+
+/// Submits `answer` with `aoc submit` and returns the output of aoc-cli and whether the answer was accepted.
+fn submit(
+    mut cmd: Command,
+    day: u8,
+    part: u8,
+    year: i16,
+    answer: &str,
+) -> Result<(bool, String), String> {
+    cmd.args([
+        "--year",
+        &year.to_string(),
+        "--day",
+        &day.to_string(),
+        "submit",
+        &part.to_string(),
+        answer,
+    ]);
+
+    let cmd_output = cmd
+        .output()
+        .map_err(|e| format!("failed to spawn aoc-cli: {}", e))?;
+
+    io::stderr()
+        .write_all(&cmd_output.stderr)
+        .expect("could not write cmd stderr to pipe.");
+
+    let output = String::from_utf8_lossy(&cmd_output.stdout).to_string();
+
+    if !cmd_output.status.success() {
+        return Err(format!(
+            "aoc-cli exited with {}:\n{}",
+            cmd_output.status, output
+        ));
+    }
+
+    Ok((output.contains("That's the right answer"), output))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!(
+                "Need to specify a day and a part (as integers). example: `cargo submit 7 1`"
+            );
+            process::exit(1);
+        }
+    };
+
+    if !(1..=2).contains(&args.part) {
+        eprintln!("Part must be 1 or 2, got {}.", args.part);
+        process::exit(1);
+    }
+
+    let solutions = advent_of_code::days::all();
+    let Some(solution) = solutions
+        .iter()
+        .find(|solution| solution.year() == args.year && solution.day() == args.day)
+    else {
+        eprintln!(
+            "Day {} of {} has no solution yet. Try running `cargo scaffold {} --year {}`.",
+            args.day, args.year, args.day, args.year
+        );
+        process::exit(1);
+    };
+
+    let input = match advent_of_code::try_read_file(args.year, "inputs", args.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let result = match args.part {
+        1 => runner::run_part(
+            args.year,
+            args.day,
+            1,
+            |input| solution.part_one(input),
+            &input,
+            None,
+        ),
+        _ => runner::run_part(
+            args.year,
+            args.day,
+            2,
+            |input| solution.part_two(input),
+            &input,
+            None,
+        ),
+    };
+
+    if let Some(error) = result.error {
+        eprintln!("Part {} failed: {}", args.part, error);
+        process::exit(1);
+    }
+
+    let Some(answer) = result.answer else {
+        eprintln!("Part {} of day {} is not solved yet.", args.part, args.day);
+        process::exit(1);
+    };
+
+    // only the letters a drawing spells can be submitted.
+    if let Answer::Art(art) = &answer {
+        eprintln!(
+            "The answer is a drawing that could not be read as letters, submit the letters it shows with `aoc submit` instead:\n{}",
+            art
+        );
+        process::exit(1);
+    }
+
+    println!(
+        "🎄 Answer for {}, day {:02}, part {}: {}",
+        args.year, args.day, args.part, answer
+    );
+
+    match Answers::load(args.year, args.day).map(|answers| answers.get(args.part).cloned()) {
+        Ok(Some(known)) if known.matches(&answer) => {
+            println!("This answer is already recorded as correct, not submitting it again.");
+            process::exit(0);
+        }
+        Ok(Some(known)) => {
+            eprintln!("Note: the recorded answer for this part is {}.", known);
+        }
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Failed to load answers: {}", e);
+            process::exit(1);
+        }
+    }
+
+    // check if aoc binary exists and is callable.
+    if Command::new("aoc").arg("-V").output().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    println!("Submitting answer with aoc-cli...");
+
+    let accepted = match submit(
+        Command::new("aoc"),
+        args.day,
+        args.part,
+        args.year,
+        &answer.to_string(),
+    ) {
+        Ok((accepted, output)) => {
+            println!("{}", output.trim());
+            accepted
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    if !accepted {
+        process::exit(1);
+    }
+
+    match Answers::record(args.year, args.day, args.part, &answer) {
+        Ok(path) => {
+            println!("---");
+            println!("🎄 Recorded answer in \"{}\".", path.display());
+        }
+        Err(e) => {
+            eprintln!("could not record answer: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// Puts a fake `aoc` executable that logs its arguments and prints `response` in a fresh directory.
+    fn fake_aoc(name: &str, response: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_submit_{}_{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let script = dir.join("aoc");
+        fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$@\" > \"{}\"\necho \"{}\"\n",
+                dir.join("args").display(),
+                response
+            ),
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        dir
+    }
+
+    fn aoc_on_path(dir: &PathBuf) -> Command {
+        let mut cmd = Command::new("aoc");
+        cmd.env("PATH", dir);
+        cmd
+    }
+
+    #[test]
+    fn test_submit_accepted() {
+        let dir = fake_aoc(
+            "accepted",
+            "That's the right answer! You are one gold star closer.",
+        );
+
+        let (accepted, output) = submit(aoc_on_path(&dir), 7, 2, 2021, "24933642").unwrap();
+
+        assert!(accepted);
+        assert!(output.contains("gold star"));
+        assert_eq!(
+            fs::read_to_string(dir.join("args")).unwrap(),
+            "--year 2021 --day 7 submit 2 24933642\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit_rejected() {
+        let dir = fake_aoc(
+            "rejected",
+            "That's not the right answer; your answer is too low.",
+        );
+
+        let (accepted, _) = submit(aoc_on_path(&dir), 1, 1, 2022, "1").unwrap();
+
+        assert!(!accepted);
+        assert_eq!(
+            fs::read_to_string(dir.join("args")).unwrap(),
+            "--year 2022 --day 1 submit 1 1\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
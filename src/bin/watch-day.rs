@@ -0,0 +1,276 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use advent_of_code::{examples, input, ANSI_BOLD, ANSI_RESET};
+
+struct Args {
+    day: u8,
+    year: i16,
+    interval: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        day: args.free_from_str()?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(advent_of_code::DEFAULT_YEAR),
+        interval: args
+            .opt_value_from_fn("--interval", |s| s.parse().map(Duration::from_millis))?
+            .unwrap_or(Duration::from_millis(500)),
+    })
+}
+
+/// The modification times of `paths`, and of the files directly inside the ones that are directories.
+/// Missing paths are left out, so creating or deleting a file counts as a change too.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let modified = |path: &Path| fs::metadata(path).and_then(|meta| meta.modified()).ok();
+    let mut times = BTreeMap::new();
+
+    for path in paths {
+        match fs::read_dir(path) {
+            Ok(entries) => {
+                for entry in entries.flatten() {
+                    if let Some(time) = modified(&entry.path()) {
+                        times.insert(entry.path(), time);
+                    }
+                }
+            }
+            Err(_) => {
+                if let Some(time) = modified(path) {
+                    times.insert(path.clone(), time);
+                }
+            }
+        }
+    }
+
+    times
+}
+
+/// The paths that were created, modified or deleted between two snapshots.
+fn changed_paths<'a>(
+    before: &'a BTreeMap<PathBuf, SystemTime>,
+    after: &'a BTreeMap<PathBuf, SystemTime>,
+) -> Vec<&'a Path> {
+    let mut changed: Vec<&Path> = after
+        .iter()
+        .filter(|(path, time)| before.get(*path) != Some(time))
+        .chain(before.iter().filter(|(path, _)| !after.contains_key(*path)))
+        .map(|(path, _)| path.as_path())
+        .collect();
+    changed.sort();
+    changed
+}
+
+fn cargo(root: &Path, args: &[&str]) -> Result<Output, String> {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    Command::new(cargo)
+        .args(args)
+        .current_dir(root)
+        .output()
+        .map_err(|e| format!("failed to spawn cargo: {}", e))
+}
+
+/// What a part produced, as shown to the user: the answer, `failed: <error>` or `not solved`.
+fn outcomes(json: &str) -> Result<BTreeMap<u8, String>, String> {
+    let results: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|e| format!("could not read the results: {}", e))?;
+
+    Ok(results
+        .iter()
+        .filter_map(|result| {
+            let part = result["part"].as_u64()? as u8;
+            let outcome = match (&result["answer"], &result["error"]) {
+                (serde_json::Value::String(answer), _) => answer.clone(),
+                (serde_json::Value::Number(answer), _) => answer.to_string(),
+                (_, serde_json::Value::String(error)) => format!("failed: {}", error),
+                _ => "not solved".to_string(),
+            };
+            Some((part, outcome))
+        })
+        .collect())
+}
+
+/// A line per part comparing its outcome with the one of the previous run.
+/// Multi-line answers like ASCII art are printed below their line when they change.
+fn diff(previous: Option<&BTreeMap<u8, String>>, current: &BTreeMap<u8, String>) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, now)| {
+            let before = previous.and_then(|previous| previous.get(part));
+            let label = format!("Part {}:", part);
+            match before {
+                Some(before) if before == now && now.contains('\n') => {
+                    format!("{} unchanged", label)
+                }
+                Some(before) if before == now => format!("{} {} (unchanged)", label, now),
+                _ if now.contains('\n') => format!("{}\n{}", label, now.trim_end()),
+                Some(before) if !before.contains('\n') => {
+                    format!("{} {} → {}", label, before, now)
+                }
+                _ => format!("{} {}", label, now),
+            }
+        })
+        .collect()
+}
+
+/// Runs the day's tests and, if they pass, the day on its input. Returns the outcomes of the parts.
+fn run(root: &Path, bin: &str) -> Result<BTreeMap<u8, String>, String> {
+    let tests = cargo(root, &["test", "--quiet", "--bin", bin])?;
+    if !tests.status.success() {
+        return Err(format!(
+            "Examples failed:\n{}{}",
+            String::from_utf8_lossy(&tests.stdout),
+            String::from_utf8_lossy(&tests.stderr)
+        ));
+    }
+    println!("Examples passed.");
+
+    let solve = cargo(
+        root,
+        &["run", "--quiet", "--bin", bin, "--", "--format", "json"],
+    )?;
+    if !solve.status.success() {
+        return Err(format!(
+            "Running on the input failed:\n{}",
+            String::from_utf8_lossy(&solve.stderr)
+        ));
+    }
+
+    outcomes(&String::from_utf8_lossy(&solve.stdout))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!(
+                "Need to specify a day (as integer), e.g. `cargo watch-day 7`: {}",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let root = input::root_dir();
+    let bin = format!("{}-{:02}", args.year, args.day);
+    let paths = vec![
+        root.join("src").join("bin").join(format!("{}.rs", bin)),
+        examples::dir(args.year, args.day),
+        input::path(args.year, "inputs", args.day, "txt"),
+    ];
+
+    println!("🎄 Watching {} for changes:", bin);
+    for path in &paths {
+        println!("  {}", path.display());
+    }
+
+    let mut previous: Option<BTreeMap<u8, String>> = None;
+    let mut times = BTreeMap::new();
+    let mut first = true;
+
+    loop {
+        let now = snapshot(&paths);
+        if first || now != times {
+            if !first {
+                let changed: Vec<String> = changed_paths(&times, &now)
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect();
+                println!(
+                    "\n{}--- changed: {} ---{}",
+                    ANSI_BOLD,
+                    changed.join(", "),
+                    ANSI_RESET
+                );
+            }
+            first = false;
+
+            match run(&root, &bin) {
+                Ok(outcomes) => {
+                    for line in diff(previous.as_ref(), &outcomes) {
+                        println!("{}", line);
+                    }
+                    previous = Some(outcomes);
+                }
+                Err(e) => eprintln!("{}", e),
+            }
+
+            // files saved while the run was going on trigger another run.
+            times = now;
+        }
+
+        thread::sleep(args.interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(outcomes: &[(u8, &str)]) -> BTreeMap<u8, String> {
+        outcomes
+            .iter()
+            .map(|(part, outcome)| (*part, outcome.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let json = r#"[
+            {"year": 2022, "day": 10, "part": 1, "answer": 13140, "elapsed_ns": 1},
+            {"year": 2022, "day": 10, "part": 2, "answer": null, "elapsed_ns": 1, "error": "day 10, line 1: oops"}
+        ]"#;
+
+        assert_eq!(
+            outcomes(json).unwrap(),
+            parts(&[(1, "13140"), (2, "failed: day 10, line 1: oops")])
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let before = parts(&[(1, "13"), (2, "#.\n.#\n")]);
+
+        assert_eq!(diff(None, &before), vec!["Part 1: 13", "Part 2:\n#.\n.#"]);
+        assert_eq!(
+            diff(Some(&before), &before),
+            vec!["Part 1: 13 (unchanged)", "Part 2: unchanged"]
+        );
+        assert_eq!(
+            diff(Some(&before), &parts(&[(1, "6090"), (2, "not solved")])),
+            vec!["Part 1: 13 → 6090", "Part 2: not solved"]
+        );
+    }
+
+    #[test]
+    fn test_changed_paths() {
+        let t = SystemTime::UNIX_EPOCH;
+        let before = BTreeMap::from([
+            (PathBuf::from("a"), t),
+            (PathBuf::from("b"), t),
+            (PathBuf::from("c"), t),
+        ]);
+        let after = BTreeMap::from([
+            (PathBuf::from("a"), t),
+            (PathBuf::from("b"), t + Duration::from_secs(1)),
+            (PathBuf::from("d"), t),
+        ]);
+
+        assert_eq!(
+            changed_paths(&before, &after),
+            vec![Path::new("b"), Path::new("c"), Path::new("d")]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+/*
+ * Register a day here once it compiles, to run it with `cargo all`: one line per day.
+ * Days that are not listed still run on their own with `cargo solve`, so a day in progress
+ * cannot break the other days.
+ */
+use std::sync::Arc;
+
+#[cfg(not(test))]
+use crate::solution::Day;
+use crate::solution::Solution;
+
+/// Defines [`all`] from the `(year, day) => "bin/<year>-<day>.rs"` lines below.
+macro_rules! days {
+    ($(($year:literal, $day:literal) => $path:literal,)*) => {
+        /// The solutions of every registered day, in the order they are listed.
+        #[cfg(not(test))]
+        pub fn all() -> Vec<Arc<dyn Solution>> {
+            vec![$({
+                #[path = $path]
+                #[allow(dead_code)]
+                mod day;
+                Arc::new(Day::new($year, $day, day::part_one, day::part_two)) as Arc<dyn Solution>
+            },)*]
+        }
+
+        // the day binaries run their own tests, the library's tests would only run them twice.
+        #[cfg(test)]
+        pub fn all() -> Vec<Arc<dyn Solution>> {
+            vec![]
+        }
+    };
+}
+
+days! {
+    (2022, 1) => "bin/2022-01.rs",
+    (2022, 2) => "bin/2022-02.rs",
+    (2022, 3) => "bin/2022-03.rs",
+    (2022, 4) => "bin/2022-04.rs",
+    (2022, 5) => "bin/2022-05.rs",
+    (2022, 6) => "bin/2022-06.rs",
+    (2022, 7) => "bin/2022-07.rs",
+    (2022, 8) => "bin/2022-08.rs",
+    (2022, 9) => "bin/2022-09.rs",
+    (2022, 10) => "bin/2022-10.rs",
+    (2022, 11) => "bin/2022-11.rs",
+    (2022, 12) => "bin/2022-12.rs",
+    (2022, 13) => "bin/2022-13.rs",
+}
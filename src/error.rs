@@ -0,0 +1,157 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+
+/// Why a part did not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The part has no solution yet. Reported as "not solved" rather than as an error.
+    Unsolved,
+    /// The input does not look like the solution expects it to.
+    Parse {
+        /// Filled in by the runner.
+        day: Option<u8>,
+        /// The 1-based line of the input.
+        line: Option<usize>,
+        /// The offending text, usually the whole line.
+        text: String,
+        reason: String,
+    },
+    /// The input was fine, but the part failed while running, e.g. because a number overflowed.
+    Runtime {
+        /// Filled in by the runner.
+        day: Option<u8>,
+        reason: String,
+    },
+}
+
+impl AocError {
+    pub fn parse(text: &str, reason: impl Display) -> Self {
+        AocError::Parse {
+            day: None,
+            line: None,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    pub fn runtime(reason: impl Display) -> Self {
+        AocError::Runtime {
+            day: None,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Attaches the 1-based `line` and, unless more specific text was recorded, its `text`.
+    /// A line that is already set is treated as relative to `line`.
+    pub fn at_line(self, line: usize, text: &str) -> Self {
+        match self {
+            AocError::Parse {
+                day,
+                line: inner,
+                text: inner_text,
+                reason,
+            } => AocError::Parse {
+                day,
+                line: Some(inner.map_or(line, |inner| line + inner - 1)),
+                text: if inner_text.is_empty() {
+                    text.to_string()
+                } else {
+                    inner_text
+                },
+                reason,
+            },
+            error => error,
+        }
+    }
+
+    pub fn in_day(self, day: u8) -> Self {
+        match self {
+            AocError::Parse {
+                line, text, reason, ..
+            } => AocError::Parse {
+                day: Some(day),
+                line,
+                text,
+                reason,
+            },
+            AocError::Runtime { reason, .. } => AocError::Runtime {
+                day: Some(day),
+                reason,
+            },
+            error => error,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Unsolved => write!(f, "not solved"),
+            AocError::Parse {
+                day,
+                line,
+                text,
+                reason,
+            } => {
+                if let Some(day) = day {
+                    write!(f, "day {:02}, ", day)?;
+                }
+                if let Some(line) = line {
+                    write!(f, "line {}: ", line)?;
+                }
+                if text.is_empty() {
+                    write!(f, "could not parse the input: {}", reason)
+                } else {
+                    write!(f, "could not parse \"{}\": {}", text, reason)
+                }
+            }
+            AocError::Runtime { day, reason } => {
+                if let Some(day) = day {
+                    write!(f, "day {:02}, ", day)?;
+                }
+                write!(f, "{}", reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+/// Lets `?` be used on integer parsing; [`parse_lines`](crate::parse::parse_lines) fills in the line.
+impl From<ParseIntError> for AocError {
+    fn from(e: ParseIntError) -> Self {
+        AocError::parse("", e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsolved() {
+        assert_eq!(
+            AocError::Unsolved.at_line(3, "x").in_day(1),
+            AocError::Unsolved
+        );
+        assert_eq!(AocError::Unsolved.to_string(), "not solved");
+        assert_eq!(
+            AocError::parse("", "there is no start").to_string(),
+            "could not parse the input: there is no start"
+        );
+    }
+
+    #[test]
+    fn test_runtime() {
+        let error = AocError::runtime("round 3, monkey 1: the worry level overflowed");
+        assert_eq!(error.clone().at_line(3, "x"), error);
+        assert_eq!(
+            error.in_day(11).to_string(),
+            "day 11, round 3, monkey 1: the worry level overflowed"
+        );
+    }
+}
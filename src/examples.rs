@@ -0,0 +1,260 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::answers::Answers;
+use crate::solution::PartOutput;
+
+/// Name of the file that holds the expected answers of a day's examples.
+pub const EXPECTED_FILE: &str = "expected.toml";
+
+/// A named example input, e.g. `src/y2022/examples/09/larger.txt`, and its expected answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub expected: Answers,
+}
+
+/// The directory holding the examples of a day, e.g. `<root>/src/y2022/examples/09`.
+pub fn dir(year: i16, day: u8) -> PathBuf {
+    crate::input::root_dir().join(crate::day_dir(year, "examples", day))
+}
+
+/// Loads every `<name>.txt` of a day's examples directory, sorted by name.
+///
+/// The expected answers are read from the tables of `expected.toml`, keyed by example name:
+///
+/// ```toml
+/// [larger]
+/// part_one = 88
+/// part_two = 36
+/// ```
+pub fn load(year: i16, day: u8) -> Result<Vec<Example>, String> {
+    load_dir(&dir(year, day))
+}
+
+fn load_dir(dir: &Path) -> Result<Vec<Example>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    let mut inputs = BTreeMap::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("{}: {}", dir.display(), e))?
+            .path();
+
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            let name = path.file_stem().unwrap_or_default().to_string_lossy();
+            let input =
+                fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            inputs.insert(name.to_string(), input);
+        }
+    }
+
+    let expected_path = dir.join(EXPECTED_FILE);
+    let mut expected: BTreeMap<String, Answers> = match fs::read_to_string(&expected_path) {
+        Ok(s) => toml::from_str(&s).map_err(|e| format!("{}: {}", expected_path.display(), e))?,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+        Err(e) => return Err(format!("{}: {}", expected_path.display(), e)),
+    };
+
+    // a typo in a table name would otherwise silently skip the example.
+    if let Some(name) = expected.keys().find(|name| !inputs.contains_key(*name)) {
+        return Err(format!(
+            "{}: there is no example named \"{}\"",
+            expected_path.display(),
+            name
+        ));
+    }
+
+    Ok(inputs
+        .into_iter()
+        .map(|(name, input)| Example {
+            expected: expected.remove(&name).unwrap_or_default(),
+            name,
+            input,
+        })
+        .collect())
+}
+
+/// Runs `func` on every example of a day that has an expected answer for `part`,
+/// panicking with a list of all mismatches, or if no example has an expected answer
+/// for `part`. Meant to be called from a day's tests.
+pub fn check<R: PartOutput>(year: i16, day: u8, part: u8, func: impl Fn(&str) -> R) {
+    let examples = load(year, day).unwrap_or_else(|e| panic!("could not load examples: {}", e));
+    check_examples(&examples, part, func);
+}
+
+fn check_examples<R: PartOutput>(examples: &[Example], part: u8, func: impl Fn(&str) -> R) {
+    // a test that checks nothing would pass for a part that was never tried on an example.
+    assert!(
+        examples
+            .iter()
+            .any(|example| example.expected.get(part).is_some()),
+        "no example has an expected answer for part {}, add one to {}",
+        part,
+        EXPECTED_FILE
+    );
+
+    let failures: Vec<String> = examples
+        .iter()
+        .filter_map(|example| {
+            let expected = example.expected.get(part)?;
+            let got = match func(&example.input).into_result() {
+                Ok(answer) if answer.matches(expected) => return None,
+                Ok(answer) => format!("{:?}", answer.to_string()),
+                Err(e) => e.to_string(),
+            };
+
+            Some(format!(
+                "example \"{}\": expected {:?}, got {}",
+                example.name,
+                expected.to_string(),
+                got
+            ))
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "part {} failed on {} example(s):\n{}",
+        part,
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+    use crate::answer::Answer;
+
+    fn example_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_examples_{}_{}", name, process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for (file, contents) in files {
+            fs::write(dir.join(file), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_load_dir() {
+        let dir = example_dir(
+            "load",
+            &[
+                ("small.txt", "R 4\n"),
+                ("larger.txt", "R 5\n"),
+                ("notes.md", "ignored"),
+                (
+                    EXPECTED_FILE,
+                    "[small]\npart_one = 13\n\n[larger]\npart_two = \"36\"\n",
+                ),
+            ],
+        );
+
+        let examples = load_dir(&dir).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "larger".to_string(),
+                    input: "R 5\n".to_string(),
+                    expected: Answers {
+                        part_one: None,
+                        part_two: Some(Answer::Int(36)),
+                    },
+                },
+                Example {
+                    name: "small".to_string(),
+                    input: "R 4\n".to_string(),
+                    expected: Answers {
+                        part_one: Some(Answer::Int(13)),
+                        part_two: None,
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_dir_unknown_example() {
+        let dir = example_dir(
+            "unknown",
+            &[("small.txt", ""), (EXPECTED_FILE, "[smal]\npart_one = 1\n")],
+        );
+
+        let err = load_dir(&dir).unwrap_err();
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(err.contains("no example named \"smal\""), "{}", err);
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let examples = vec![
+            Example {
+                name: "a".to_string(),
+                input: "1 2".to_string(),
+                expected: Answers {
+                    part_one: Some(Answer::Int(3)),
+                    part_two: None,
+                },
+            },
+            Example {
+                name: "b".to_string(),
+                input: "2 2".to_string(),
+                expected: Answers::default(),
+            },
+        ];
+
+        check_examples(&examples, 1, |input| {
+            Some(
+                input
+                    .split(' ')
+                    .map(|n| n.parse::<u32>().unwrap())
+                    .sum::<u32>(),
+            )
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "no example has an expected answer for part 2")]
+    fn test_check_examples_without_expected() {
+        let examples = vec![Example {
+            name: "a".to_string(),
+            input: String::new(),
+            expected: Answers {
+                part_one: Some(Answer::Int(3)),
+                part_two: None,
+            },
+        }];
+
+        check_examples(&examples, 2, |_| None::<u32>);
+    }
+
+    #[test]
+    #[should_panic(expected = "example \"a\": expected \"3\", got not solved")]
+    fn test_check_examples_mismatch() {
+        let examples = vec![Example {
+            name: "a".to_string(),
+            input: String::new(),
+            expected: Answers {
+                part_one: Some(Answer::Int(3)),
+                part_two: None,
+            },
+        }];
+
+        check_examples(&examples, 1, |_| None::<u32>);
+    }
+}
@@ -2,3 +2,458 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::error::AocError;
+use crate::parse::parse_lines;
+
+pub mod bitmap;
+pub mod graph;
+
+/// A position on a [`Grid`] or on an unbounded plane. `x` grows to the right, `y` downwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+    pub const UP: Point = Point::new(0, -1);
+    pub const DOWN: Point = Point::new(0, 1);
+    pub const LEFT: Point = Point::new(-1, 0);
+    pub const RIGHT: Point = Point::new(1, 0);
+
+    /// The four orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+    /// All eight directions including the diagonals, clockwise from up.
+    pub const ALL: [Point; 8] = [
+        Point::new(0, -1),
+        Point::new(1, -1),
+        Point::new(1, 0),
+        Point::new(1, 1),
+        Point::new(0, 1),
+        Point::new(-1, 1),
+        Point::new(-1, 0),
+        Point::new(-1, -1),
+    ];
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The point with both coordinates clamped to -1, 0 or 1.
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, n: isize) -> Point {
+        Point::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its cells in row-major order. Returns `None` if they do not fill whole rows.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    /// Parses a character map, converting every character with `f`.
+    /// Fails if `f` does or if the lines are not all equally long.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, AocError>,
+    ) -> Result<Self, AocError> {
+        let mut width = None;
+
+        let rows = parse_lines(input, |line| {
+            let row = line.chars().map(&mut f).collect::<Result<Vec<_>, _>>()?;
+
+            match width {
+                Some(width) if width != row.len() => Err(AocError::parse(
+                    line,
+                    format!("expected {} columns, found {}", width, row.len()),
+                )),
+                _ => {
+                    width = Some(row.len());
+                    Ok(row)
+                }
+            }
+        })?;
+
+        Grid::from_vec(
+            width.unwrap_or_default(),
+            rows.into_iter().flatten().collect(),
+        )
+        .ok_or_else(|| AocError::parse("", "the map is empty"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (0..self.width as isize).contains(&p.x) && (0..self.height as isize).contains(&p.y)
+    }
+
+    /// The position of `p` in the row-major storage, if it is on the grid.
+    pub fn index_of(&self, p: Point) -> Option<usize> {
+        self.contains(p)
+            .then(|| p.y as usize * self.width + p.x as usize)
+    }
+
+    pub fn point_of(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        self.index_of(p).map(|i| &mut self.cells[i])
+    }
+
+    /// All points of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    /// All cells with their points in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    /// The first point whose cell matches `pred`, in row-major order.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells.iter().position(pred).map(|i| self.point_of(i))
+    }
+
+    /// The up to four orthogonal neighbours of `p` that are on the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Point::ORTHOGONAL
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|&q| self.contains(q))
+    }
+
+    /// The up to eight neighbours of `p`, including diagonals, that are on the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Point::ALL
+            .into_iter()
+            .map(move |d| p + d)
+            .filter(|&q| self.contains(q))
+    }
+
+    /// # Panics
+    /// If `y` is not a row of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+
+    /// # Panics
+    /// If `x` is not a column of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The cells from `from` (exclusive) in steps of `direction` up to the edge of the grid.
+    /// `direction` must not be [`Point::ORIGIN`], or the ray never ends.
+    pub fn ray(&self, from: Point, direction: Point) -> impl Iterator<Item = (Point, &T)> + '_ {
+        let mut p = from;
+
+        std::iter::from_fn(move || {
+            p += direction;
+            self.get(p).map(|cell| (p, cell))
+        })
+    }
+
+    /// Flips the grid over its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Rotates the grid by 90° clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .flat_map(|x| {
+                (0..self.height)
+                    .rev()
+                    .map(move |y| self.cells[y * self.width + x].clone())
+            })
+            .collect();
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Rotates the grid by 90° counterclockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/// # Panics
+/// If the point is not on the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        match self.index_of(p) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{:?} is out of bounds of a {}x{} grid",
+                p, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// # Panics
+/// If the point is not on the grid.
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        match self.index_of(p) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{:?} is out of bounds of a {}x{} grid",
+                p, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Renders the grid row by row, with a newline after each row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input: &str) -> Grid<char> {
+        Grid::parse(input, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\n456\n", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::parse(&c.to_string(), "not a digit"))
+        })
+        .unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+
+        let err = Grid::parse("12\n1x", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| AocError::parse("", format!("'{}' is not a digit", c)))
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: could not parse \"1x\": 'x' is not a digit"
+        );
+
+        let err = Grid::parse("ab\nabc", Ok).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: could not parse \"abc\": expected 2 columns, found 3"
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = chars("abc\ndef\nghi");
+
+        let corner: Vec<_> = grid.neighbours4(Point::ORIGIN).map(|p| grid[p]).collect();
+        assert_eq!(corner, vec!['b', 'd']);
+
+        let center: String = grid
+            .neighbours8(Point::new(1, 1))
+            .map(|p| grid[p])
+            .collect();
+        assert_eq!(center, "bcfihgda");
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let grid = chars("abc\ndef\nghi");
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+
+        let ray: String = grid
+            .ray(Point::new(0, 2), Point::new(1, -1))
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(ray, "ec");
+        assert_eq!(grid.ray(Point::new(0, 1), Point::LEFT).count(), 0);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = chars("abc\ndef");
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn test_position_and_index_mut() {
+        let mut grid = chars("S..\n..E");
+
+        let end = grid.position(|&c| c == 'E').unwrap();
+        assert_eq!(end, Point::new(2, 1));
+        assert_eq!(grid.index_of(end), Some(5));
+        assert_eq!(grid.point_of(5), end);
+
+        grid[end] = '#';
+        assert_eq!(grid.to_string(), "S..\n..#\n");
+    }
+
+    #[test]
+    fn test_point() {
+        let p = Point::new(3, -2);
+        assert_eq!(p + Point::UP * 2, Point::new(3, -4));
+        assert_eq!(p.manhattan(Point::ORIGIN), 5);
+        assert_eq!((-p).signum(), Point::new(-1, 1));
+    }
+}
@@ -0,0 +1,160 @@
+//! Black and white images, like the letters a puzzle has a screen or a laser draw,
+//! and the ways to look at them: as text, as Unicode blocks or as an image file.
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use super::Grid;
+use crate::error::AocError;
+
+/// A black and white image, where `true` is a lit pixel.
+pub type Bitmap = Grid<bool>;
+
+/// How [`render`] draws a bitmap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Style {
+    /// A line per row with `#` for lit and `.` for dark pixels, which [`crate::ocr`] reads.
+    #[default]
+    Text,
+    /// A line per two rows with `▀`, `▄` and `█`, so the pixels come out about square.
+    HalfBlocks,
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Style::Text),
+            "half-blocks" => Ok(Style::HalfBlocks),
+            _ => Err(format!(
+                "unknown style \"{}\", expected text or half-blocks",
+                s
+            )),
+        }
+    }
+}
+
+/// Reads a bitmap back from text, with `#` and `█` as lit pixels, e.g. a drawn answer.
+pub fn parse(text: &str) -> Result<Bitmap, AocError> {
+    Grid::parse(text.trim_end_matches('\n'), |c| Ok(c == '#' || c == '█'))
+}
+
+/// Draws `bitmap` as text, every line ending with a newline.
+pub fn render(bitmap: &Bitmap, style: Style) -> String {
+    let mut out = String::with_capacity((bitmap.width() + 1) * bitmap.height());
+
+    match style {
+        Style::Text => {
+            for row in bitmap.rows() {
+                out.extend(row.iter().map(|&lit| if lit { '#' } else { '.' }));
+                out.push('\n');
+            }
+        }
+        Style::HalfBlocks => {
+            for y in (0..bitmap.height()).step_by(2) {
+                let top = bitmap.row(y);
+                // an odd last row is drawn above a dark one.
+                let bottom = (y + 1 < bitmap.height()).then(|| bitmap.row(y + 1));
+
+                out.extend((0..bitmap.width()).map(|x| {
+                    match (top[x], bottom.is_some_and(|bottom| bottom[x])) {
+                        (true, true) => '█',
+                        (true, false) => '▀',
+                        (false, true) => '▄',
+                        (false, false) => ' ',
+                    }
+                }));
+                out.push('\n');
+            }
+        }
+    }
+
+    out
+}
+
+/// Encodes `bitmap` as a binary PBM image, with lit pixels black on white
+/// and every pixel drawn as a `scale` × `scale` square.
+pub fn pbm(bitmap: &Bitmap, scale: usize) -> Vec<u8> {
+    let scale = scale.max(1);
+    let (width, height) = (bitmap.width() * scale, bitmap.height() * scale);
+    let mut image = format!("P4\n{} {}\n", width, height).into_bytes();
+    image.reserve(width.div_ceil(8) * height);
+
+    for row in bitmap.rows() {
+        // every line of the image is padded to whole bytes.
+        let mut line = vec![0u8; width.div_ceil(8)];
+        for (x, _) in row.iter().enumerate().filter(|(_, &lit)| lit) {
+            for x in x * scale..(x + 1) * scale {
+                line[x / 8] |= 0x80 >> (x % 8);
+            }
+        }
+        for _ in 0..scale {
+            image.extend_from_slice(&line);
+        }
+    }
+
+    image
+}
+
+/// Writes `bitmap` to `path` as a PBM image, see [`pbm`].
+pub fn write_pbm(path: &Path, bitmap: &Bitmap, scale: usize) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, pbm(bitmap, scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::process;
+
+    use super::*;
+
+    fn bitmap(s: &str) -> Bitmap {
+        parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_render() {
+        let image = bitmap("#..#\n.##.\n#...");
+
+        assert_eq!(render(&image, Style::Text), "#..#\n.##.\n#...\n");
+        assert_eq!(render(&image, Style::HalfBlocks), "▀▄▄▀\n▀   \n");
+        assert_eq!("half-blocks".parse(), Ok(Style::HalfBlocks));
+        assert!("png".parse::<Style>().is_err());
+
+        assert_eq!(parse(&render(&image, Style::Text)), Ok(image));
+        assert!(parse("#.\n#").is_err());
+    }
+
+    #[test]
+    fn test_pbm() {
+        let image = bitmap("#.\n.#");
+
+        assert_eq!(pbm(&image, 1), b"P4\n2 2\n\x80\x40".to_vec());
+        assert_eq!(
+            pbm(&image, 5),
+            [
+                b"P4\n10 10\n".as_slice(),
+                &[0xf8, 0x00].repeat(5),
+                &[0x07, 0xc0].repeat(5),
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_write_pbm() {
+        let dir = env::temp_dir().join(format!("aoc_bitmap_{}", process::id()));
+        let path = dir.join("frames").join("image.pbm");
+        let image = bitmap("#..\n.##");
+
+        write_pbm(&path, &image, 2).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), pbm(&image, 2));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,320 @@
+//! Shortest paths on graphs that are given by a neighbour function instead of being built
+//! up front, e.g. `|&p| grid.neighbours4(p)`. Nodes are numbered in the order they are
+//! reached, so a search only ever stores the part of the graph it visited.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+pub type Cost = u64;
+
+/// A path found by a search, from one of its starts to the node it ended at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: Cost,
+}
+
+impl<N> Path<N> {
+    /// The number of edges on the path.
+    pub fn steps(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn end(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+}
+
+/// Every node a search reached, with the cost of and the predecessor on its shortest path.
+#[derive(Debug, Clone)]
+pub struct SearchTree<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    costs: Vec<Cost>,
+    preds: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> SearchTree<N> {
+    fn new() -> Self {
+        SearchTree {
+            nodes: vec![],
+            index: HashMap::new(),
+            costs: vec![],
+            preds: vec![],
+        }
+    }
+
+    /// Records that `node` can be reached at `cost`, returning its index
+    /// unless it was already known to be reachable at least as cheaply.
+    fn relax(&mut self, node: N, cost: Cost, pred: Option<usize>) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&i) if self.costs[i] <= cost => None,
+            Some(&i) => {
+                self.costs[i] = cost;
+                self.preds[i] = pred;
+                Some(i)
+            }
+            None => {
+                let i = self.nodes.len();
+                self.index.insert(node.clone(), i);
+                self.nodes.push(node);
+                self.costs.push(cost);
+                self.preds.push(pred);
+                Some(i)
+            }
+        }
+    }
+
+    fn path_from_index(&self, mut i: usize) -> Path<N> {
+        let cost = self.costs[i];
+        let mut nodes = vec![self.nodes[i].clone()];
+        while let Some(pred) = self.preds[i] {
+            nodes.push(self.nodes[pred].clone());
+            i = pred;
+        }
+        nodes.reverse();
+
+        Path { nodes, cost }
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// The cost of the cheapest path to `node`, if it was reached.
+    pub fn cost(&self, node: &N) -> Option<Cost> {
+        self.index.get(node).map(|&i| self.costs[i])
+    }
+
+    /// The cheapest path to `node`, if it was reached.
+    pub fn path_to(&self, node: &N) -> Option<Path<N>> {
+        self.index.get(node).map(|&i| self.path_from_index(i))
+    }
+
+    /// All reached nodes with their costs, in the order they were first reached.
+    pub fn iter(&self) -> impl Iterator<Item = (&N, Cost)> + '_ {
+        self.nodes.iter().zip(self.costs.iter().copied())
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Breadth-first search from all `starts` at once, where every edge costs 1.
+/// Returns the shortest path to the first node that `is_goal`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (tree, goal) = bfs_until(starts, neighbours, is_goal);
+    goal.map(|i| tree.path_from_index(i))
+}
+
+/// Breadth-first search from all `starts` that visits every reachable node.
+pub fn bfs_all<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> SearchTree<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_until(starts, neighbours, |_| false).0
+}
+
+fn bfs_until<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (SearchTree<N>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = SearchTree::new();
+    let mut todo: VecDeque<usize> = starts
+        .into_iter()
+        .filter_map(|start| tree.relax(start, 0, None))
+        .collect();
+
+    while let Some(i) = todo.pop_front() {
+        if is_goal(&tree.nodes[i]) {
+            return (tree, Some(i));
+        }
+
+        let cost = tree.costs[i] + 1;
+        for next in neighbours(&tree.nodes[i]) {
+            todo.extend(tree.relax(next, cost, Some(i)));
+        }
+    }
+
+    (tree, None)
+}
+
+/// Dijkstra's algorithm from all `starts` at once, where `neighbours` yields
+/// every neighbour with the cost of the edge to it.
+/// Returns the cheapest path to the first node that `is_goal`.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/// Dijkstra's algorithm from all `starts` that visits every reachable node.
+pub fn dijkstra_all<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+) -> SearchTree<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    astar_until(starts, neighbours, |_| 0, |_| false).0
+}
+
+/// A* search from all `starts` at once. `heuristic` estimates the remaining cost to a goal
+/// and must never overestimate it, e.g. the manhattan distance on a grid.
+/// Returns the cheapest path to the first node that `is_goal`.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> Cost,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    let (tree, goal) = astar_until(starts, neighbours, heuristic, is_goal);
+    goal.map(|i| tree.path_from_index(i))
+}
+
+fn astar_until<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> Cost,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (SearchTree<N>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, Cost)>,
+{
+    let mut tree = SearchTree::new();
+    let mut todo = BinaryHeap::new();
+
+    for start in starts {
+        let h = heuristic(&start);
+        if let Some(i) = tree.relax(start, 0, None) {
+            todo.push(Reverse((h, 0, i)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, i))) = todo.pop() {
+        // a cheaper path to the node was found after this entry was queued.
+        if cost > tree.costs[i] {
+            continue;
+        }
+        if is_goal(&tree.nodes[i]) {
+            return (tree, Some(i));
+        }
+
+        for (next, edge) in neighbours(&tree.nodes[i]) {
+            let h = heuristic(&next);
+            if let Some(j) = tree.relax(next, cost + edge, Some(i)) {
+                todo.push(Reverse((cost + edge + h, cost + edge, j)));
+            }
+        }
+    }
+
+    (tree, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 costs 3, 0 -> 3 costs 5, 4 is unreachable.
+    fn edges(n: &u8) -> Vec<(u8, Cost)> {
+        match n {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let unweighted = |n: &u8| edges(n).into_iter().map(|(m, _)| m);
+
+        let path = bfs([0], unweighted, |&n| n == 3).unwrap();
+        assert_eq!(path.nodes, vec![0, 3]);
+        assert_eq!(path.steps(), 1);
+
+        assert_eq!(bfs([0], unweighted, |&n| n == 4), None);
+
+        let tree = bfs_all([1], unweighted);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.cost(&3), Some(2));
+        assert!(!tree.contains(&0));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(path.nodes, vec![0, 1, 2, 3]);
+        assert_eq!(path.cost, 3);
+
+        let tree = dijkstra_all([0], edges);
+        assert_eq!(tree.path_to(&3), Some(path));
+        assert_eq!(tree.path_to(&4), None);
+    }
+
+    #[test]
+    fn test_multi_source() {
+        let path = dijkstra([0, 2], edges, |&n| n == 3).unwrap();
+        assert_eq!((*path.start(), *path.end(), path.cost), (2, 3, 1));
+    }
+
+    #[test]
+    fn test_astar() {
+        // walk around a wall on an open plane.
+        let wall = |x: i32, y: i32| x == 2 && y < 4;
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(move |&(x, y)| x.abs() < 10 && y.abs() < 10 && !wall(x, y))
+                .map(|p| (p, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (x.abs_diff(4) + y.abs_diff(0)) as Cost;
+
+        let path = astar([(0, 0)], neighbours, heuristic, |&p| p == (4, 0)).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(
+            path.cost,
+            dijkstra([(0, 0)], neighbours, |&p| p == (4, 0))
+                .unwrap()
+                .cost
+        );
+    }
+}
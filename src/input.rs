@@ -0,0 +1,139 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::convert::Infallible;
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The repository root: `$CARGO_MANIFEST_DIR` if it is set (e.g. by `cargo run`),
+/// otherwise the directory the crate was built in.
+pub fn root_dir() -> PathBuf {
+    env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")))
+}
+
+/// Resolves a day's file against `root`, e.g. `<root>/src/y2022/inputs/01.txt`.
+///
+/// Inputs are looked up in `<input_dir>/<year>/<day>.txt` instead if `input_dir` is set.
+pub fn resolve(
+    root: &Path,
+    input_dir: Option<&Path>,
+    year: i16,
+    folder: &str,
+    day: u8,
+    extension: &str,
+) -> PathBuf {
+    match input_dir {
+        Some(dir) if folder == "inputs" => dir
+            .join(year.to_string())
+            .join(format!("{:02}.{}", day, extension)),
+        _ => root.join(crate::day_file(year, folder, day, extension)),
+    }
+}
+
+/// Resolves a day's file against [`root_dir`], honouring `$AOC_INPUT_DIR` for inputs.
+pub fn path(year: i16, folder: &str, day: u8, extension: &str) -> PathBuf {
+    let input_dir = env::var_os("AOC_INPUT_DIR").map(PathBuf::from);
+    resolve(
+        &root_dir(),
+        input_dir.as_deref(),
+        year,
+        folder,
+        day,
+        extension,
+    )
+}
+
+/// Where a day binary reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// The input file of a day, see [`path`].
+    pub fn for_day(year: i16, day: u8) -> Self {
+        Input::File(path(year, "inputs", day, "txt"))
+    }
+
+    /// `--input <path>` replaces the input file of the day; `--input -` reads stdin.
+    pub fn from_args(
+        args: &mut pico_args::Arguments,
+        year: i16,
+        day: u8,
+    ) -> Result<Self, pico_args::Error> {
+        let input = args.opt_value_from_os_str("--input", |s: &OsStr| {
+            Ok::<_, Infallible>(if s == "-" {
+                Input::Stdin
+            } else {
+                Input::File(PathBuf::from(s))
+            })
+        })?;
+
+        Ok(input.unwrap_or_else(|| Input::for_day(year, day)))
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| format!("could not read input from stdin: {}", e))?;
+                Ok(input)
+            }
+            Input::File(path) => fs::read_to_string(path)
+                .map_err(|e| format!("could not read \"{}\": {}", path.display(), e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let root = Path::new("/repo");
+
+        assert_eq!(
+            resolve(root, None, 2022, "inputs", 1, "txt"),
+            Path::new("/repo/src/y2022/inputs/01.txt")
+        );
+        assert_eq!(
+            resolve(root, Some(Path::new("/inputs")), 2021, "inputs", 7, "txt"),
+            Path::new("/inputs/2021/07.txt")
+        );
+        assert_eq!(
+            resolve(root, Some(Path::new("/inputs")), 2022, "examples", 7, "txt"),
+            Path::new("/repo/src/y2022/examples/07.txt")
+        );
+    }
+
+    #[test]
+    fn test_from_args() {
+        let mut args = pico_args::Arguments::from_vec(vec!["--input".into(), "-".into()]);
+        assert_eq!(Input::from_args(&mut args, 2022, 1).unwrap(), Input::Stdin);
+
+        let mut args =
+            pico_args::Arguments::from_vec(vec!["--input".into(), "/tmp/big.txt".into()]);
+        assert_eq!(
+            Input::from_args(&mut args, 2022, 1).unwrap(),
+            Input::File(PathBuf::from("/tmp/big.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_missing_file_names_path() {
+        let err = Input::File(PathBuf::from("/does/not/exist/01.txt"))
+            .read()
+            .unwrap_err();
+        assert!(err.contains("\"/does/not/exist/01.txt\""), "{}", err);
+    }
+}
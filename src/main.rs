@@ -2,45 +2,323 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use std::num::NonZeroUsize;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+use advent_of_code::output::{self, Art, Format};
+use advent_of_code::runner::{self, Options, PartResult};
+use advent_of_code::solution::Solution;
+
+/// How long a part may run before it is given up on, unless `--timeout` says otherwise.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+struct Args {
+    year: i16,
+    /// The days to run, in order.
+    days: Vec<u8>,
+    /// Only report the parts that fail, implies `--check`.
+    only_failing: bool,
+    format: Format,
+    options: Options,
+    /// How many days run at the same time.
+    jobs: NonZeroUsize,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or(advent_of_code::DEFAULT_YEAR);
+    let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+    let jobs = args
+        .opt_value_from_str(["-j", "--jobs"])?
+        .unwrap_or(NonZeroUsize::MIN);
+
+    let only_failing = args.contains("--only-failing");
+
+    // a hanging day must not keep the remaining days from running.
+    let mut options = Options::from_args(&mut args)?;
+    options.timeout.get_or_insert(DEFAULT_TIMEOUT);
+    options.check |= only_failing;
+
+    // frames and traces of days running at the same time would be mixed up.
+    if jobs.get() > 1 && options.visualize.is_some() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--jobs cannot be combined with --visualize".to_string(),
+        });
+    }
+    if jobs.get() > 1 && options.trace {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--jobs cannot be combined with --trace".to_string(),
+        });
+    }
+    // the peak memory is counted for the whole program, a part would reset the peak of another.
+    if jobs.get() > 1 && options.memory {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: "--jobs cannot be combined with --memory".to_string(),
+        });
+    }
+
+    // free arguments are only left over once all options are taken.
+    let days = args
+        .opt_free_from_fn(parse_days)?
+        .unwrap_or_else(|| (1..=25).collect());
+
+    Ok(Args {
+        year,
+        days,
+        only_failing,
+        format,
+        options,
+        jobs,
+    })
+}
+
+/// Parses a selection of days like `3-7,12` into the sorted days it contains.
+fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let day = |s: &str| match s.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", s.trim())),
+    };
+
+    let mut days = vec![];
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (day(first)?, day(last)?);
+                if first > last {
+                    return Err(format!("\"{}\" is an empty range of days", item.trim()));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(day(item)?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+
+    Ok(days)
+}
+
+/// A registered day to run, or `None` if it has no input.
+type Job<'a> = Option<(&'a Arc<dyn Solution>, String)>;
+
+fn run_job(job: &Job, options: &Options, on_result: impl FnMut(&PartResult)) -> Vec<PartResult> {
+    match job {
+        Some((solution, input)) => runner::run_day(solution, input, options, on_result),
+        None => vec![],
+    }
+}
+
+/// Prints the parts of a day as text, with the day's header before the first part shown.
+/// With `--only-failing`, a day without failing parts is not printed at all.
+struct DayReport<'a> {
+    day: u8,
+    is_text: bool,
+    only_failing: bool,
+    art: &'a Art,
+    header_printed: bool,
+}
+
+impl<'a> DayReport<'a> {
+    fn new(day: u8, is_text: bool, only_failing: bool, art: &'a Art) -> Self {
+        DayReport {
+            day,
+            is_text,
+            only_failing,
+            art,
+            header_printed: false,
+        }
+    }
+
+    fn header(&mut self) {
+        if !self.header_printed {
+            output::print_day_header(self.day);
+            self.header_printed = true;
+        }
+    }
+
+    fn part(&mut self, result: &PartResult) {
+        if self.is_text && (!self.only_failing || result.is_failing()) {
+            self.header();
+            output::print_part(result, self.art);
+        }
+    }
+
+    fn no_input(&mut self) {
+        if self.is_text && !self.only_failing {
+            self.header();
+            println!("No input.");
+        }
+    }
+}
+
+/// Runs the days on `threads` threads, handing the results of every day to `on_day`
+/// in day order as soon as it and all days before it are done.
+fn run_parallel(
+    jobs: &[Job],
+    options: &Options,
+    threads: usize,
+    mut on_day: impl FnMut(usize, Vec<PartResult>),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..threads.min(jobs.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(job) = jobs.get(i) else {
+                    break;
+                };
+                if sender.send((i, run_job(job, options, |_| {}))).is_err() {
+                    break;
                 }
-            );
+            });
+        }
+        drop(sender);
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+        let mut done: Vec<Option<Vec<PartResult>>> = vec![None; jobs.len()];
+        let mut reported = 0;
+        for (i, results) in receiver {
+            done[i] = Some(results);
+            while let Some(results) = done.get_mut(reported).and_then(Option::take) {
+                on_day(reported, results);
+                reported += 1;
             }
+        }
+    });
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let is_text = args.format == Format::Text;
+    let solutions = advent_of_code::days::all();
+
+    // days that are not registered are left out instead of being reported as unsolved.
+    let (days, jobs): (Vec<u8>, Vec<Job>) = args
+        .days
+        .iter()
+        .filter_map(|&day| {
+            let solution = solutions
+                .iter()
+                .find(|solution| solution.year() == args.year && solution.day() == day)?;
+            let input = advent_of_code::try_read_file(args.year, "inputs", day).ok();
+            Some((day, input.map(|input| (solution, input))))
         })
-        .sum();
+        .unzip();
+
+    if days.is_empty() {
+        eprintln!("None of the selected days of {} is registered in src/days.rs.", args.year);
+        process::exit(1);
+    }
+
+    let start = Instant::now();
+    let mut results: Vec<PartResult> = vec![];
+
+    if args.jobs.get() == 1 {
+        for (&day, job) in days.iter().zip(&jobs) {
+            let mut report = DayReport::new(day, is_text, args.only_failing, &args.options.art);
+            results.extend(run_job(job, &args.options, |result| report.part(result)));
+            if job.is_none() {
+                report.no_input();
+            }
+        }
+    } else {
+        run_parallel(&jobs, &args.options, args.jobs.get(), |i, day_results| {
+            let mut report = DayReport::new(days[i], is_text, args.only_failing, &args.options.art);
+            day_results.iter().for_each(|result| report.part(result));
+            if jobs[i].is_none() {
+                report.no_input();
+            }
+            results.extend(day_results);
+        });
+    }
+
+    let wall_clock = start.elapsed();
+
+    if is_text {
+        output::print_total(
+            results.iter().map(|result| result.elapsed).sum(),
+            wall_clock,
+        );
+        output::print_timeouts(&results);
+        if args.options.check {
+            output::print_check_summary(&results);
+        }
+        if args.only_failing && !results.iter().any(PartResult::is_failing) {
+            println!("No failing parts.");
+        }
+    } else if args.only_failing {
+        let failing: Vec<PartResult> = results
+            .iter()
+            .filter(|result| result.is_failing())
+            .cloned()
+            .collect();
+        output::print_results(args.format, &failing);
+    } else {
+        output::print_results(args.format, &results);
+    }
+
+    if runner::has_regressions(&results) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::solution::Day;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3-7,12"), Ok(vec![3, 4, 5, 6, 7, 12]));
+        assert_eq!(parse_days("12, 3,3-4"), Ok(vec![3, 4, 12]));
+        assert_eq!(parse_days("25"), Ok(vec![25]));
+        assert_eq!(
+            parse_days("0-3"),
+            Err("\"0\" is not a day between 1 and 25".to_string())
+        );
+        assert_eq!(
+            parse_days("7-3"),
+            Err("\"7-3\" is an empty range of days".to_string())
+        );
+        assert!(parse_days("3,").is_err());
+    }
+
+    #[test]
+    fn test_panicking_day_is_reported() {
+        let solution: Arc<dyn Solution> = Arc::new(Day::new(
+            2022,
+            1,
+            |_| Some(1),
+            |_| -> Option<u32> { panic!("boom") },
+        ));
+        let job: Job = Some((&solution, String::new()));
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+        let mut reported = vec![];
+        let results = run_job(&job, &Options::default(), |result| {
+            reported.push(result.clone())
+        });
+        assert_eq!(results, reported);
+        assert_eq!(
+            results.iter().map(|result| result.part).collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(results[1].error.as_deref(), Some("panicked: boom"));
+        assert!(results[1].is_failing());
+    }
 }
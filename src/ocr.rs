@@ -0,0 +1,204 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answer::Answer;
+use crate::error::AocError;
+
+/// The 4 × 6 letters, e.g. of 2016 day 8, 2019 days 8 and 11, 2021 day 13 and 2022 day 10.
+const SMALL: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6 × 10 letters of 2018 day 10.
+#[rustfmt::skip]
+const LARGE: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+type Pixels = Vec<Vec<bool>>;
+
+/// `#` and `█` are lit pixels, anything else is dark.
+fn pixels<'a>(rows: impl IntoIterator<Item = &'a str>) -> Pixels {
+    rows.into_iter()
+        .map(|row| row.chars().map(|c| c == '#' || c == '█').collect())
+        .collect()
+}
+
+/// Splits an image at its dark columns into the glyphs between them,
+/// each with the column it starts at.
+fn glyphs(image: &Pixels) -> Vec<(usize, Pixels)> {
+    let width = image.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| image.iter().any(|row| row.get(x) == Some(&true));
+
+    let mut glyphs = vec![];
+    let mut x = 0;
+    while x < width {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && lit(x) {
+            x += 1;
+        }
+        let glyph = image
+            .iter()
+            .map(|row| (start..x).map(|x| row.get(x) == Some(&true)).collect())
+            .collect();
+        glyphs.push((start, glyph));
+    }
+
+    glyphs
+}
+
+fn font<const H: usize>(letters: &[(char, [&str; H])]) -> Vec<(char, Pixels)> {
+    letters
+        .iter()
+        .flat_map(|(letter, rows)| {
+            glyphs(&pixels(rows.iter().copied()))
+                .into_iter()
+                .map(|(_, glyph)| (*letter, glyph))
+        })
+        .collect()
+}
+
+/// Reads the letters drawn by `art` in one of the fonts of the puzzles, 6 or 10 rows high.
+///
+/// ```
+/// let art = ".##..###.\n#..#.#..#\n#..#.###.\n####.#..#\n#..#.#..#\n#..#.###.";
+/// assert_eq!(advent_of_code::ocr::decode(art), Ok("AB".to_string()));
+/// ```
+pub fn decode(art: &str) -> Result<String, AocError> {
+    let image = pixels(art.trim_end_matches('\n').lines());
+    let font = match image.len() {
+        6 => font(SMALL),
+        10 => font(LARGE),
+        rows => {
+            return Err(AocError::parse(
+                "",
+                format!("expected letters 6 or 10 rows high, found {} rows", rows),
+            ))
+        }
+    };
+
+    let glyphs = glyphs(&image);
+    if glyphs.is_empty() {
+        return Err(AocError::parse("", "found no letters"));
+    }
+
+    glyphs
+        .iter()
+        .map(|(start, glyph)| {
+            font.iter()
+                .find(|(_, letter)| letter == glyph)
+                .map(|(letter, _)| *letter)
+                .ok_or_else(|| {
+                    AocError::parse("", format!("unknown letter at column {}", start + 1))
+                })
+        })
+        .collect()
+}
+
+/// The answer drawn by `art`: the letters it spells, keeping the image to show along with them,
+/// or just the image if it does not spell letters, e.g. the drawing of an example.
+pub fn answer(art: String) -> Answer {
+    match decode(&art) {
+        Ok(text) => Answer::Letters { text, art },
+        Err(_) => Answer::from(art),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draws `text` like the puzzles do, with a dark column after every letter.
+    fn draw<const H: usize>(letters: &[(char, [&str; H])], text: &str) -> String {
+        (0..H)
+            .map(|y| {
+                text.chars()
+                    .map(|c| {
+                        let (_, rows) = letters.iter().find(|(letter, _)| *letter == c).unwrap();
+                        format!("{}.", rows[y])
+                    })
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_decode() {
+        let alphabet: String = SMALL.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(decode(&draw(SMALL, &alphabet)), Ok(alphabet));
+
+        let alphabet: String = LARGE.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(decode(&draw(LARGE, &alphabet)), Ok(alphabet));
+
+        // letters drawn with blocks on spaces read the same.
+        let art = draw(SMALL, "EHZ").replace('#', "█").replace('.', " ");
+        assert_eq!(decode(&art), Ok("EHZ".to_string()));
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode("#.#\n.#.").unwrap_err().to_string(),
+            "could not parse the input: expected letters 6 or 10 rows high, found 2 rows"
+        );
+        assert!(decode("....\n....\n....\n....\n....\n....").is_err());
+
+        let mut art = draw(SMALL, "AB");
+        art.replace_range(5..6, ".");
+        assert_eq!(
+            decode(&art).unwrap_err().to_string(),
+            "could not parse the input: unknown letter at column 6"
+        );
+    }
+
+    #[test]
+    fn test_answer() {
+        let art = draw(SMALL, "HI");
+        assert_eq!(
+            answer(art.clone()),
+            Answer::Letters {
+                text: "HI".to_string(),
+                art
+            }
+        );
+        assert_eq!(answer("##\n##\n".to_string()), Answer::from("##\n##\n"));
+    }
+}
@@ -0,0 +1,493 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::answers::Check;
+use crate::helpers::bitmap::{self, Style};
+use crate::memory;
+use crate::runner::PartResult;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Human-readable output, printed while the solutions run.
+    #[default]
+    Text,
+    /// A JSON array of all results, printed once everything has run.
+    Json,
+    /// A CSV table of all results, printed once everything has run.
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+/// How drawn answers, like the letters of a CRT, are shown.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Art {
+    /// Printed in a [`Style`].
+    Print(Style),
+    /// Written as images to `<dir>/<year>-<day>-part<part>.pbm` and printed as text.
+    Pbm(PathBuf),
+}
+
+impl Default for Art {
+    fn default() -> Self {
+        Art::Print(Style::Text)
+    }
+}
+
+impl Art {
+    /// Every pixel of a drawing as a square of this many pixels of the image.
+    const PBM_SCALE: usize = 4;
+
+    /// Reads `--art <text|half-blocks>` or `--art-pbm <dir>`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        if let Some(dir) = args.opt_value_from_str("--art-pbm")? {
+            return Ok(Art::Pbm(dir));
+        }
+        Ok(Art::Print(
+            args.opt_value_from_str("--art")?.unwrap_or_default(),
+        ))
+    }
+
+    /// Redraws `art` in the chosen style, or leaves it as it is if it is no bitmap.
+    fn show(&self, art: &str) -> String {
+        match self {
+            Art::Print(Style::Text) | Art::Pbm(_) => art.to_string(),
+            Art::Print(style) => bitmap::parse(art)
+                .map(|bitmap| bitmap::render(&bitmap, *style))
+                .unwrap_or_else(|_| art.to_string()),
+        }
+    }
+
+    /// Writes the drawing of `result` as an image if drawings are written to images,
+    /// and returns where.
+    pub fn write(&self, result: &PartResult) -> Result<Option<PathBuf>, String> {
+        let (Art::Pbm(dir), Some(art)) = (self, result.answer.as_ref().and_then(Answer::art))
+        else {
+            return Ok(None);
+        };
+
+        let path = dir.join(format!(
+            "{}-{:02}-part{}.pbm",
+            result.year, result.day, result.part
+        ));
+        let bitmap = bitmap::parse(art).map_err(|e| e.to_string())?;
+        bitmap::write_pbm(&path, &bitmap, Self::PBM_SCALE)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        Ok(Some(path))
+    }
+}
+
+pub fn print_day_header(day: u8) {
+    println!("----------");
+    println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+    println!("----------");
+}
+
+pub fn print_part(result: &PartResult, art: &Art) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, result.part, ANSI_RESET);
+
+    let answer = result.answer.as_ref().map(|answer| {
+        let answer = match answer {
+            Answer::Art(drawing) => art.show(drawing),
+            answer => answer.to_string(),
+        };
+        match &result.check {
+            Some(check @ Check::Wrong { expected }) => {
+                format!("{} {} expected {}", answer, check.symbol(), expected)
+            }
+            Some(check) => format!("{} {}", answer, check.symbol()),
+            None => answer,
+        }
+    });
+
+    let memory = result
+        .peak_memory
+        .map(|bytes| format!(", peak memory: {}", memory::format_bytes(bytes)))
+        .unwrap_or_default();
+
+    match (&answer, &result.stats) {
+        (Some(answer), Some(stats)) => {
+            println!(
+                "{} {}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} samples{}){}",
+                answer,
+                ANSI_ITALIC,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                stats.min,
+                stats.samples,
+                memory,
+                ANSI_RESET
+            );
+        }
+        (Some(answer), None) => {
+            println!(
+                "{} {}(elapsed: {:.2?}{}){}",
+                answer, ANSI_ITALIC, result.elapsed, memory, ANSI_RESET
+            );
+        }
+        (None, _) => {
+            let status = match &result.error {
+                Some(error) if result.timed_out => format!("{}.", error),
+                Some(error) => format!("failed: {}", error),
+                None => "not solved.".to_string(),
+            };
+
+            match &result.check {
+                Some(check @ Check::Wrong { expected }) => {
+                    println!("{} {} expected {}", status, check.symbol(), expected)
+                }
+                _ => println!("{}", status),
+            }
+        }
+    }
+
+    // letters are easier to trust next to the drawing they were read from.
+    if let Some(Answer::Letters { art: drawing, .. }) = &result.answer {
+        println!("{}", art.show(drawing).trim_end());
+    }
+}
+
+/// Prints the summed time of all parts and the time the whole run took,
+/// which is shorter if days ran in parallel.
+pub fn print_total(total: Duration, wall_clock: Duration) {
+    println!(
+        "{}Total:{} {}{:.2}ms (wall clock: {:.2}ms){}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        wall_clock.as_secs_f64() * 1000_f64,
+        ANSI_RESET
+    );
+}
+
+pub fn print_check_summary(results: &[PartResult]) {
+    let count = |name: &str| {
+        results
+            .iter()
+            .filter(|result| result.check.as_ref().map(Check::name) == Some(name))
+            .count()
+    };
+
+    println!(
+        "{}Checked:{} {} ✓ correct, {} ✗ wrong, {} ? unknown",
+        ANSI_BOLD,
+        ANSI_RESET,
+        count("correct"),
+        count("wrong"),
+        count("unknown")
+    );
+}
+
+/// Lists the parts that ran into the timeout, if any did.
+pub fn print_timeouts(results: &[PartResult]) {
+    let timed_out: Vec<String> = results
+        .iter()
+        .filter(|result| result.timed_out)
+        .map(|result| format!("day {:02} part {}", result.day, result.part))
+        .collect();
+
+    if !timed_out.is_empty() {
+        println!(
+            "{}Timed out:{} {}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            timed_out.join(", ")
+        );
+    }
+}
+
+/// Prints the machine-readable formats. Text output is printed part by part instead.
+pub fn print_results(format: Format, results: &[PartResult]) {
+    match format {
+        Format::Text => {}
+        Format::Json => println!("{}", to_json(results)),
+        Format::Csv => print!("{}", to_csv(results)),
+    }
+}
+
+pub fn to_json(results: &[PartResult]) -> String {
+    serde_json::to_string_pretty(results).unwrap()
+}
+
+/// The benchmark, check, memory and error columns are only present if any of the results
+/// was benchmarked, checked, measured or failed.
+pub fn to_csv(results: &[PartResult]) -> String {
+    let with_stats = results.iter().any(|result| result.stats.is_some());
+    let with_check = results.iter().any(|result| result.check.is_some());
+    let with_memory = results.iter().any(|result| result.peak_memory.is_some());
+    let with_error = results.iter().any(|result| result.error.is_some());
+
+    let mut out = String::from("year,day,part,answer,elapsed_ns");
+    if with_stats {
+        out.push_str(",samples,min_ns,median_ns,mean_ns,stddev_ns");
+    }
+    if with_check {
+        out.push_str(",check,expected");
+    }
+    if with_memory {
+        out.push_str(",peak_memory_bytes");
+    }
+    if with_error {
+        out.push_str(",error");
+    }
+    out.push('\n');
+
+    for result in results {
+        out.push_str(&format!(
+            "{},{},{},{},{}",
+            result.year,
+            result.day,
+            result.part,
+            csv_field(
+                &result
+                    .answer
+                    .as_ref()
+                    .map(Answer::to_string)
+                    .unwrap_or_default()
+            ),
+            result.elapsed.as_nanos()
+        ));
+
+        match (with_stats, &result.stats) {
+            (true, Some(stats)) => out.push_str(&format!(
+                ",{},{},{},{},{}",
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            )),
+            (true, None) => out.push_str(",,,,,"),
+            (false, _) => {}
+        }
+
+        match (with_check, &result.check) {
+            (true, Some(Check::Wrong { expected })) => {
+                out.push_str(&format!(",wrong,{}", csv_field(&expected.to_string())))
+            }
+            (true, Some(check)) => out.push_str(&format!(",{},", check.name())),
+            (true, None) => out.push_str(",,"),
+            (false, _) => {}
+        }
+
+        if with_memory {
+            out.push(',');
+            if let Some(bytes) = result.peak_memory {
+                out.push_str(&bytes.to_string());
+            }
+        }
+
+        if with_error {
+            out.push_str(&format!(
+                ",{}",
+                csv_field(result.error.as_deref().unwrap_or_default())
+            ));
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                year: 2022,
+                day: 5,
+                part: 1,
+                answer: Some(Answer::from("CMZ")),
+                elapsed: Duration::from_micros(12),
+                stats: None,
+                check: None,
+                peak_memory: None,
+                timed_out: false,
+                error: None,
+            },
+            PartResult {
+                year: 2022,
+                day: 10,
+                part: 2,
+                answer: Some(Answer::from("##..\n#,\"#")),
+                elapsed: Duration::from_nanos(3),
+                stats: None,
+                check: None,
+                peak_memory: None,
+                timed_out: false,
+                error: None,
+            },
+            PartResult {
+                year: 2022,
+                day: 12,
+                part: 1,
+                answer: None,
+                elapsed: Duration::ZERO,
+                stats: None,
+                check: None,
+                peak_memory: None,
+                timed_out: false,
+                error: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "year,day,part,answer,elapsed_ns\n2022,5,1,CMZ,12000\n2022,10,2,\"##..\n#,\"\"#\",3\n2022,12,1,,0\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv_with_stats() {
+        let mut results = results();
+        results[0].stats = Some(Stats {
+            samples: 10,
+            min: Duration::from_micros(10),
+            median: Duration::from_micros(12),
+            mean: Duration::from_micros(13),
+            stddev: Duration::from_micros(2),
+        });
+
+        let csv = to_csv(&results[..1]);
+        assert_eq!(
+            csv,
+            "year,day,part,answer,elapsed_ns,samples,min_ns,median_ns,mean_ns,stddev_ns\n\
+             2022,5,1,CMZ,12000,10,10000,12000,13000,2000\n"
+        );
+        assert!(to_csv(&results).ends_with("\n2022,12,1,,0,,,,,\n"));
+    }
+
+    #[test]
+    fn test_to_csv_with_check() {
+        let mut results = results();
+        results[0].check = Some(Check::Correct);
+        results[1].check = Some(Check::Wrong {
+            expected: Answer::from("a,b"),
+        });
+
+        let csv = to_csv(&results);
+        assert!(csv.starts_with(
+            "year,day,part,answer,elapsed_ns,check,expected\n2022,5,1,CMZ,12000,correct,\n"
+        ));
+        assert!(csv.ends_with(",3,wrong,\"a,b\"\n2022,12,1,,0,,\n"));
+    }
+
+    #[test]
+    fn test_to_csv_with_error() {
+        let mut results = results();
+        results[2].error =
+            Some("day 12, line 1: could not parse \"S1\": invalid square".to_string());
+
+        let csv = to_csv(&results);
+        assert!(csv.starts_with("year,day,part,answer,elapsed_ns,error\n2022,5,1,CMZ,12000,\n"));
+        assert!(csv.ends_with(
+            "\n2022,12,1,,0,\"day 12, line 1: could not parse \"\"S1\"\": invalid square\"\n"
+        ));
+    }
+
+    #[test]
+    fn test_to_csv_with_memory_and_timeout() {
+        let mut results = results();
+        results[0].peak_memory = Some(2048);
+        results[2].timed_out = true;
+        results[2].error = Some("timed out after 1.00s".to_string());
+
+        let csv = to_csv(&results);
+        assert!(csv.starts_with(
+            "year,day,part,answer,elapsed_ns,peak_memory_bytes,error\n2022,5,1,CMZ,12000,2048,\n"
+        ));
+        assert!(csv.ends_with("\n2022,12,1,,0,,timed out after 1.00s\n"));
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&results)).unwrap();
+        assert_eq!(json[0]["peak_memory_bytes"], 2048);
+        assert_eq!(json[1].get("timed_out"), None);
+        assert_eq!(json[2]["timed_out"], true);
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&results())).unwrap();
+        assert_eq!(
+            json[0],
+            serde_json::json!({"year": 2022, "day": 5, "part": 1, "answer": "CMZ", "elapsed_ns": 12000})
+        );
+        assert_eq!(json[2]["answer"], serde_json::Value::Null);
+
+        let mut results = results();
+        results[0].check = Some(Check::Wrong {
+            expected: Answer::from("MCD"),
+        });
+        results[2].answer = Some(Answer::Int(2713310158));
+        let json: serde_json::Value = serde_json::from_str(&to_json(&results)).unwrap();
+        assert_eq!(
+            json[0]["check"],
+            serde_json::json!({"status": "wrong", "expected": "MCD"})
+        );
+        assert_eq!(json[2]["answer"], serde_json::json!(2713310158u64));
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("csv".parse::<Format>(), Ok(Format::Csv));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_art() {
+        assert_eq!(Art::Print(Style::HalfBlocks).show("#.\n.#\n"), "▀▄\n");
+        assert_eq!(Art::Print(Style::HalfBlocks).show("#.\n#"), "#.\n#");
+        assert_eq!(Art::Print(Style::Text).show("#.\n.#\n"), "#.\n.#\n");
+
+        let dir = std::env::temp_dir().join(format!("aoc_art_{}", std::process::id()));
+        let art = Art::Pbm(dir.clone());
+        let mut results = results();
+        assert_eq!(art.write(&results[0]), Ok(None));
+
+        results[1].answer = Some(Answer::from("#.\n.#\n"));
+        let path = dir.join("2022-10-part2.pbm");
+        assert_eq!(art.write(&results[1]), Ok(Some(path.clone())));
+        assert!(std::fs::read(&path).unwrap().starts_with(b"P4\n8 8\n"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,268 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::AocError;
+
+/// Parses every line of `input` with `f`, attaching the line number and text to errors.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    parse_split(input.lines().map(|line| (line, 1)), f)
+}
+
+/// Parses every block of lines separated by a blank line with `f`, attaching the line
+/// the block starts at, or the line within the block that `f` reported, to errors.
+pub fn parse_blocks<'a, T>(
+    input: &'a str,
+    f: impl FnMut(&'a str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    let blocks = paragraphs(input).map(|block| (block, block.split('\n').count() + 1));
+    parse_split(blocks, f)
+}
+
+fn parse_split<'a, T>(
+    parts: impl Iterator<Item = (&'a str, usize)>,
+    mut f: impl FnMut(&'a str) -> Result<T, AocError>,
+) -> Result<Vec<T>, AocError> {
+    let mut line = 1;
+
+    parts
+        .map(|(part, lines)| {
+            let result = f(part).map_err(|e| e.at_line(line, part));
+            line += lines;
+            result
+        })
+        .collect()
+}
+
+/// Splits `input` into blocks of lines separated by a blank line, ignoring trailing newlines.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches('\n').split("\n\n")
+}
+
+/// Extracts every integer from `line`, e.g. `[1, 2, -3]` from `move 1 from 2 to x=-3`.
+/// A `-` is only taken as a sign if it does not follow a digit, so `2-4` yields `[2, 4]`.
+pub fn ints<T>(line: &str) -> Result<Vec<T>, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let signed = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if signed || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+
+            let number = &line[start..i];
+            numbers.push(number.parse().map_err(|e| AocError::parse(number, e))?);
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(numbers)
+}
+
+/// Like [`ints`], but fails unless `line` contains exactly `N` integers.
+pub fn ints_n<T, const N: usize>(line: &str) -> Result<[T; N], AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    ints(line)?.try_into().map_err(|numbers: Vec<T>| {
+        let plural = if N == 1 { "" } else { "s" };
+        AocError::parse(
+            "",
+            format!("expected {} number{}, found {}", N, plural, numbers.len()),
+        )
+    })
+}
+
+/// Splits a line of a fixed-column layout into fields of `width` characters that are
+/// separated by `gap` characters, e.g. `[Z] [M]    ` into `"[Z]"`, `"[M]"` and `"   "`.
+/// The last field is cut short if the line is.
+pub fn fixed_width(line: &str, width: usize, gap: usize) -> impl Iterator<Item = &str> {
+    (0..line.len())
+        .step_by(width + gap)
+        .map_while(move |start| line.get(start..line.len().min(start + width)))
+}
+
+/// The `key: value` lines of a block, e.g. a monkey of 2022 day 11:
+///
+/// ```text
+/// Monkey 0:
+///   Starting items: 79, 98
+///   Operation: new = old * 19
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    first_line: &'a str,
+    /// The 1-based line within the block, the trimmed key and the trimmed value.
+    fields: Vec<(usize, &'a str, &'a str)>,
+}
+
+impl<'a> Fields<'a> {
+    /// Splits every line of `block` at its first `:`, failing on lines without one.
+    pub fn parse(block: &'a str) -> Result<Self, AocError> {
+        let mut line = 0;
+        let fields = parse_lines(block, |text| {
+            line += 1;
+            let (key, value) = text
+                .split_once(':')
+                .ok_or_else(|| AocError::parse("", "expected \"<key>: <value>\""))?;
+            Ok((line, key.trim(), value.trim()))
+        })?;
+
+        Ok(Fields {
+            first_line: block.lines().next().unwrap_or_default(),
+            fields,
+        })
+    }
+
+    fn find(&self, key: &str) -> Result<(usize, &'a str), AocError> {
+        self.fields
+            .iter()
+            .find(|(_, k, _)| *k == key)
+            .map(|&(line, _, value)| (line, value))
+            .ok_or_else(|| {
+                AocError::parse(self.first_line, format!("missing \"{}\"", key)).at_line(1, "")
+            })
+    }
+
+    /// The value of `key`, failing if the block has no such line.
+    pub fn get(&self, key: &str) -> Result<&'a str, AocError> {
+        self.find(key).map(|(_, value)| value)
+    }
+
+    /// Parses the value of `key` with `f`, attaching the line of the field to errors.
+    pub fn parse_with<T>(
+        &self,
+        key: &str,
+        f: impl FnOnce(&'a str) -> Result<T, AocError>,
+    ) -> Result<T, AocError> {
+        let (line, value) = self.find(key)?;
+        f(value).map_err(|e| e.at_line(line, value))
+    }
+
+    /// The keys in the order they appear in the block.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.fields.iter().map(|(_, key, _)| *key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(s: &str) -> Result<u32, AocError> {
+        Ok(s.trim().parse()?)
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(parse_lines("1\n2\n3", number), Ok(vec![1, 2, 3]));
+
+        let err = parse_lines("1\n2\nthree\n4", number).unwrap_err();
+        assert_eq!(
+            err.in_day(1).to_string(),
+            "day 01, line 3: could not parse \"three\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let sum = |block: &str| parse_lines(block, number).map(|v| v.iter().sum::<u32>());
+        assert_eq!(parse_blocks("1\n2\n\n3\n\n4\n5\n", sum), Ok(vec![3, 3, 9]));
+
+        let err = parse_blocks("1\n2\n\n3\n\n4\nx", sum).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 7: could not parse \"x\": invalid digit found in string"
+        );
+
+        let err = parse_blocks("1\n\nMonkey 0:\n  x", |block| {
+            block
+                .strip_prefix("Monkey")
+                .map(|_| ())
+                .ok_or_else(|| AocError::parse("", "expected a monkey"))
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: could not parse \"1\": expected a monkey"
+        );
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<usize>("move 1 from 12 to 3"), Ok(vec![1, 12, 3]));
+        assert_eq!(ints::<i32>("x=-2, y=15"), Ok(vec![-2, 15]));
+        assert_eq!(ints::<u8>("2-4,6-8"), Ok(vec![2, 4, 6, 8]));
+        assert_eq!(ints::<u8>("--"), Ok(vec![]));
+        assert_eq!(
+            ints::<u8>("300").unwrap_err().to_string(),
+            "could not parse \"300\": number too large to fit in target type"
+        );
+
+        assert_eq!(ints_n::<u8, 2>("1 to 2"), Ok([1, 2]));
+        assert_eq!(
+            parse_lines("1 2\n1 to 2 to 3", ints_n::<u8, 2>)
+                .unwrap_err()
+                .to_string(),
+            "line 2: could not parse \"1 to 2 to 3\": expected 2 numbers, found 3"
+        );
+    }
+
+    #[test]
+    fn test_fixed_width() {
+        let fields = |line| fixed_width(line, 3, 1).collect::<Vec<_>>();
+        assert_eq!(fields("[Z] [M] [P]"), vec!["[Z]", "[M]", "[P]"]);
+        assert_eq!(fields("    [D]"), vec!["   ", "[D]"]);
+        assert_eq!(fields(" 1   2"), vec![" 1 ", " 2"]);
+        assert_eq!(fields(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_fields() {
+        let block = "Monkey 0:\n  Starting items: 79, 98\n  Test: divisible by x";
+        let fields = Fields::parse(block).unwrap();
+
+        assert_eq!(
+            fields.keys().collect::<Vec<_>>(),
+            vec!["Monkey 0", "Starting items", "Test"]
+        );
+        assert_eq!(fields.get("Starting items"), Ok("79, 98"));
+        assert_eq!(fields.parse_with("Starting items", ints), Ok(vec![79, 98]));
+
+        let err = parse_blocks(&format!("Test: 7\n\n{}", block), |block| {
+            Fields::parse(block)?.parse_with("Test", ints_n::<u32, 1>)
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 5: could not parse \"divisible by x\": expected 1 number, found 0"
+        );
+
+        let err = Fields::parse(block).unwrap().get("Operation").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: could not parse \"Monkey 0:\": missing \"Operation\""
+        );
+        assert!(Fields::parse("Monkey 0:\n  oops").is_err());
+    }
+}
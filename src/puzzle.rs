@@ -0,0 +1,208 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+
+/// The parts of a puzzle page that the scaffold needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The puzzle description(s) converted to Markdown.
+    pub markdown: String,
+    /// The contents of the first `<pre><code>` block, which usually holds the example input.
+    pub example: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+        } else if let Some(tag) = rest.strip_prefix('<') {
+            let Some(end) = tag.find('>') else {
+                tokens.push(Token::Text(rest));
+                break;
+            };
+
+            let tag_content = tag[..end].trim_end_matches('/');
+            rest = &tag[end + 1..];
+
+            if let Some(name) = tag_content.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim().to_lowercase()));
+            } else if !tag_content.starts_with('!') {
+                let (name, attrs) = tag_content
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((tag_content, ""));
+                tokens.push(Token::Open {
+                    name: name.to_lowercase(),
+                    attrs,
+                });
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+fn collapse_whitespace(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Converts the `<article class="day-desc">` elements of a puzzle page to Markdown.
+pub fn parse(html: &str) -> Puzzle {
+    let mut markdown = String::new();
+    let mut example = None;
+
+    let mut in_article = false;
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut block = String::new();
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open { name, attrs } if name == "article" => {
+                in_article = attr(attrs, "class").is_some_and(|c| c.contains("day-desc"));
+            }
+            Token::Close(name) if name == "article" => {
+                in_article = false;
+            }
+            _ if !in_article => {}
+            Token::Open { name, attrs } => match name.as_str() {
+                "h2" | "p" | "li" => block.clear(),
+                "pre" => {
+                    block.clear();
+                    in_pre = true;
+                }
+                "code" if !in_pre => {
+                    block.push('`');
+                    in_code = true;
+                }
+                "em" if !in_pre && !in_code => block.push_str("**"),
+                "a" => {
+                    block.push('[');
+                    links.push(attr(attrs, "href").unwrap_or_default().to_string());
+                }
+                "br" => block.push('\n'),
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" => markdown.push_str(&format!("## {}\n\n", collapse_whitespace(&block))),
+                "p" => markdown.push_str(&format!("{}\n\n", collapse_whitespace(&block))),
+                "li" => markdown.push_str(&format!("- {}\n", collapse_whitespace(&block))),
+                "ul" => markdown.push('\n'),
+                "pre" => {
+                    markdown.push_str(&format!("```\n{}\n```\n\n", block.trim_end_matches('\n')));
+                    if example.is_none() {
+                        example = Some(block.clone());
+                    }
+                    in_pre = false;
+                }
+                "code" if !in_pre => {
+                    block.push('`');
+                    in_code = false;
+                }
+                "em" if !in_pre && !in_code => block.push_str("**"),
+                "a" => block.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+                _ => {}
+            },
+            Token::Text(text) => block.push_str(&decode_entities(text)),
+        }
+    }
+
+    Puzzle {
+        markdown: markdown.trim_end().to_string() + "\n",
+        example,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fixture() {
+        let puzzle = parse(include_str!("fixtures/puzzle.html"));
+
+        assert_eq!(puzzle.markdown, include_str!("fixtures/puzzle.md"));
+        assert_eq!(
+            puzzle.example,
+            Some("1000\n2000\n3000\n\n4000\n\n5000\n6000\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_without_article() {
+        let puzzle = parse("<html><body><p>Please log in.</p></body></html>");
+        assert_eq!(puzzle.markdown, "\n");
+        assert_eq!(puzzle.example, None);
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &bogus; &"),
+            "a <b> & 'c' &bogus; &"
+        );
+    }
+}
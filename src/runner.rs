@@ -0,0 +1,482 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::answer::Answer;
+use crate::answers::{Answers, Check};
+use crate::bench::{self, Stats};
+use crate::error::AocError;
+use crate::input::Input;
+use crate::memory;
+use crate::output::{self, Art, Format};
+use crate::solution::{PartOutput, Solution};
+use crate::trace;
+use crate::visualize;
+
+/// The outcome of running a single part of a day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartResult {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    /// The single measured run, or the median when benchmarking.
+    #[serde(rename = "elapsed_ns", serialize_with = "serialize_nanos")]
+    pub elapsed: Duration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<Check>,
+    /// The most heap memory allocated at once while the part ran, if it was measured.
+    #[serde(rename = "peak_memory_bytes", skip_serializing_if = "Option::is_none")]
+    pub peak_memory: Option<usize>,
+    /// Whether the part was given up on because it ran into the timeout.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    /// Why the part failed, if it did. An unsolved part has neither an answer nor an error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    /// Whether the part failed, timed out or contradicts a known answer.
+    /// An unsolved part only counts as failing if its answer is known.
+    pub fn is_failing(&self) -> bool {
+        self.error.is_some() || matches!(self.check, Some(Check::Wrong { .. }))
+    }
+}
+
+pub(crate) fn serialize_nanos<S: Serializer>(
+    elapsed: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(elapsed.as_nanos() as u64)
+}
+
+/// Runs and times a single part, or benchmarks it if `bench` is set.
+pub fn run_part<R: PartOutput>(
+    year: i16,
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> R,
+    input: &str,
+    bench: Option<&bench::Config>,
+) -> PartResult {
+    let (answer, elapsed, stats) = match bench {
+        Some(config) => {
+            let (answer, stats) = bench::bench(func, input, config);
+            (answer, stats.median, Some(stats))
+        }
+        None => {
+            let timer = Instant::now();
+            let answer = func(input);
+            (answer, timer.elapsed(), None)
+        }
+    };
+
+    let (answer, error) = match answer.into_result() {
+        Ok(answer) => (Some(answer), None),
+        Err(AocError::Unsolved) => (None, None),
+        Err(e) => (None, Some(e.in_day(day).to_string())),
+    };
+
+    PartResult {
+        year,
+        day,
+        part,
+        answer,
+        elapsed,
+        stats,
+        check: None,
+        peak_memory: None,
+        timed_out: false,
+        error,
+    }
+}
+
+/// Settings shared by the day binaries and `cargo all`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Benchmark every part instead of timing a single run.
+    pub bench: Option<bench::Config>,
+    /// Compare every answer against the answers store.
+    pub check: bool,
+    /// Render the frames that days emit while running.
+    pub visualize: Option<visualize::Config>,
+    /// Give up on a part that runs longer than this.
+    pub timeout: Option<Duration>,
+    /// Measure the peak heap memory of every part.
+    pub memory: bool,
+    /// Only run this part instead of both.
+    pub part: Option<u8>,
+    /// Print the lines that days trace.
+    pub trace: bool,
+    /// How drawn answers are shown.
+    pub art: Art,
+}
+
+impl Options {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let options = Options {
+            bench: bench::Config::from_args(args)?,
+            check: args.contains("--check"),
+            visualize: visualize::Config::from_args(args)?,
+            timeout: args.opt_value_from_fn("--timeout", parse_seconds)?,
+            memory: args.contains("--memory"),
+            part: args.opt_value_from_fn("--part", parse_part)?,
+            trace: args.contains("--trace"),
+            art: Art::from_args(args)?,
+        };
+
+        // rendering or tracing every benchmark run would only measure the output.
+        if options.bench.is_some() && options.visualize.is_some() {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--bench cannot be combined with --visualize".to_string(),
+            });
+        }
+        if options.bench.is_some() && options.trace {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--bench cannot be combined with --trace".to_string(),
+            });
+        }
+        if options.memory && !cfg!(feature = "memory") {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--memory needs the \"memory\" feature, build with --features memory"
+                    .to_string(),
+            });
+        }
+
+        Ok(options)
+    }
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let seconds: f64 = s.parse().map_err(|e| format!("{}", e))?;
+    Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("expected 1 or 2, found \"{}\"", s)),
+    }
+}
+
+/// Runs both parts of a day, or the one selected with `--part`, handing each result to `on_result` as soon as it is available.
+pub fn run_day(
+    solution: &Arc<dyn Solution>,
+    input: &str,
+    options: &Options,
+    mut on_result: impl FnMut(&PartResult),
+) -> Vec<PartResult> {
+    let (year, day) = (solution.year(), solution.day());
+
+    let answers = if options.check {
+        Some(Answers::load(year, day).unwrap_or_else(|e| {
+            eprintln!("Failed to load answers, treating them as unknown: {}", e);
+            Answers::default()
+        }))
+    } else {
+        None
+    };
+
+    let mut finish = |mut result: PartResult| {
+        if let Some(answers) = &answers {
+            result.check = Some(answers.check(&result));
+        }
+        // drawn once the part has run, so that drawing is not timed or repeated by `--bench`.
+        if let Err(e) = options.art.write(&result) {
+            eprintln!("Failed to write the drawing: {}", e);
+        }
+        on_result(&result);
+        result
+    };
+
+    let run = |part: u8| {
+        let solution = Arc::clone(solution);
+        let input = input.to_string();
+        let options = options.clone();
+
+        with_timeout(options.timeout, year, day, part, move || {
+            traced(&options, || {
+                visualized(&options, year, day, part, || {
+                    let bench = options.bench.as_ref();
+                    let baseline = options.memory.then(memory::reset_peak);
+                    let mut result = match part {
+                        1 => run_part(
+                            year,
+                            day,
+                            1,
+                            |input| solution.part_one(input),
+                            &input,
+                            bench,
+                        ),
+                        _ => run_part(
+                            year,
+                            day,
+                            2,
+                            |input| solution.part_two(input),
+                            &input,
+                            bench,
+                        ),
+                    };
+                    result.peak_memory =
+                        baseline.map(|baseline| memory::peak().saturating_sub(baseline));
+                    result
+                })
+            })
+        })
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter(|part| options.part.is_none_or(|selected| selected == *part))
+        .map(|part| finish(catch_panic(year, day, part, || run(part))))
+        .collect()
+}
+
+/// Runs `f`, turning a panic into a failed result, so that a panicking part
+/// is reported and checked like any other failure instead of disappearing.
+fn catch_panic(year: i16, day: u8, part: u8, f: impl FnOnce() -> PartResult) -> PartResult {
+    let timer = Instant::now();
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| PartResult {
+        year,
+        day,
+        part,
+        answer: None,
+        elapsed: timer.elapsed(),
+        stats: None,
+        check: None,
+        peak_memory: None,
+        timed_out: false,
+        error: Some(format!("panicked: {}", panic_message(payload.as_ref()))),
+    })
+}
+
+/// The message a panic was raised with, which is a `&str` or a `String` unless it was raised
+/// with `panic_any`.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "no message"
+    }
+}
+
+/// Runs `f` on its own thread and gives up on it after `timeout`. A part that timed out
+/// cannot be stopped, its thread keeps running in the background until the program exits.
+fn with_timeout(
+    timeout: Option<Duration>,
+    year: i16,
+    day: u8,
+    part: u8,
+    f: impl FnOnce() -> PartResult + Send + 'static,
+) -> PartResult {
+    let Some(timeout) = timeout else {
+        return f();
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .name(format!("{}-{:02} part {}", year, day, part))
+        // as much stack as the main thread usually gets, for deeply recursive solutions.
+        .stack_size(8 << 20)
+        .spawn(move || {
+            let _ = sender.send(f());
+        })
+        .expect("could not spawn a thread for the part");
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => PartResult {
+            year,
+            day,
+            part,
+            answer: None,
+            elapsed: timeout,
+            stats: None,
+            check: None,
+            peak_memory: None,
+            timed_out: true,
+            error: Some(format!("timed out after {:.2?}", timeout)),
+        },
+        // the part panicked, pass the panic on as if it had run on this thread.
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("the part finished without sending its result"),
+        },
+    }
+}
+
+/// Runs `f` with a renderer installed if `--visualize` was passed.
+fn visualized<T>(options: &Options, year: i16, day: u8, part: u8, f: impl FnOnce() -> T) -> T {
+    let Some(config) = &options.visualize else {
+        return f();
+    };
+
+    match config.renderer(year, day, part) {
+        Ok(renderer) => visualize::with_renderer(renderer, f),
+        Err(e) => {
+            eprintln!("Failed to set up the visualization: {}", e);
+            f()
+        }
+    }
+}
+
+/// Runs `f` with tracing enabled if `--trace` was passed.
+fn traced<T>(options: &Options, f: impl FnOnce() -> T) -> T {
+    if options.trace {
+        trace::with_tracing(f)
+    } else {
+        f()
+    }
+}
+
+/// Whether any of the results contradicts a known answer.
+pub fn has_regressions(results: &[PartResult]) -> bool {
+    results
+        .iter()
+        .any(|result| matches!(result.check, Some(Check::Wrong { .. })))
+}
+
+fn parse_args(
+    args: &mut pico_args::Arguments,
+    solution: &dyn Solution,
+) -> Result<(Format, Options, Input), pico_args::Error> {
+    Ok((
+        args.opt_value_from_str("--format")?.unwrap_or_default(),
+        Options::from_args(args)?,
+        Input::from_args(args, solution.year(), solution.day())?,
+    ))
+}
+
+/// Entry point of the day binaries, see [`crate::solve!`].
+///
+/// Exits with a non-zero status if the input cannot be read or `--check` finds a wrong answer.
+pub fn solve_day(solution: Arc<dyn Solution>) -> Vec<PartResult> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let (format, options, input) = match parse_args(&mut args, solution.as_ref()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let input = match input.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let results = run_day(&solution, &input, &options, |result| {
+        if format == Format::Text {
+            output::print_part(result, &options.art);
+        }
+    });
+
+    output::print_results(format, &results);
+
+    if has_regressions(&results) {
+        process::exit(1);
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finished(part: u8) -> PartResult {
+        run_part(2022, 1, part, |_| Some(42), "", None)
+    }
+
+    #[test]
+    fn test_with_timeout() {
+        let timeout = Some(Duration::from_millis(50));
+
+        let result = with_timeout(timeout, 2022, 1, 1, || finished(1));
+        assert_eq!(result.answer, Some(Answer::Int(42)));
+        assert!(!result.timed_out);
+
+        let result = with_timeout(timeout, 2022, 1, 2, || {
+            thread::sleep(Duration::from_secs(5));
+            finished(2)
+        });
+        assert!(result.timed_out);
+        assert_eq!(result.answer, None);
+        assert_eq!(result.error.as_deref(), Some("timed out after 50.00ms"));
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn test_with_timeout_passes_on_panics() {
+        with_timeout(Some(Duration::from_secs(5)), 2022, 1, 1, || panic!("boom"));
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        let result = catch_panic(2022, 1, 2, || panic!("boom {}", 7));
+        assert_eq!(result.part, 2);
+        assert_eq!(result.error.as_deref(), Some("panicked: boom 7"));
+        assert!(result.is_failing());
+
+        // a panic on the timeout thread is passed on and caught the same way.
+        let result = catch_panic(2022, 1, 1, || {
+            with_timeout(Some(Duration::from_secs(5)), 2022, 1, 1, || panic!("boom"))
+        });
+        assert_eq!(result.error.as_deref(), Some("panicked: boom"));
+    }
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("1.5"), Ok(Duration::from_millis(1500)));
+        assert!(parse_seconds("-1").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(parse_part("2"), Ok(2));
+        assert_eq!(
+            parse_part("3"),
+            Err("expected 1 or 2, found \"3\"".to_string())
+        );
+    }
+
+    #[test]
+    fn test_is_failing() {
+        let mut result = finished(1);
+        assert!(!result.is_failing());
+
+        result.check = Some(Check::Unknown);
+        assert!(!result.is_failing());
+
+        result.check = Some(Check::Wrong {
+            expected: Answer::Int(7),
+        });
+        assert!(result.is_failing());
+
+        let timed_out = with_timeout(Some(Duration::ZERO), 2022, 1, 1, || {
+            thread::sleep(Duration::from_secs(5));
+            finished(1)
+        });
+        assert!(timed_out.is_failing());
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answer::Answer;
+use crate::error::AocError;
+
+/// A day's puzzle solution that can be run in-process, also on another thread.
+pub trait Solution: Send + Sync {
+    fn year(&self) -> i16;
+    fn day(&self) -> u8;
+    fn part_one(&self, input: &str) -> Result<Answer, AocError>;
+    fn part_two(&self, input: &str) -> Result<Answer, AocError>;
+}
+
+/// What the `part_one` / `part_two` functions of a day may return:
+/// `Result<T, AocError>`, or `Option<T>` where `None` means the part is not solved yet.
+pub trait PartOutput {
+    fn into_result(self) -> Result<Answer, AocError>;
+}
+
+impl<T: Into<Answer>> PartOutput for Result<T, AocError> {
+    fn into_result(self) -> Result<Answer, AocError> {
+        self.map(Into::into)
+    }
+}
+
+impl<T: Into<Answer>> PartOutput for Option<T> {
+    fn into_result(self) -> Result<Answer, AocError> {
+        self.map(Into::into).ok_or(AocError::Unsolved)
+    }
+}
+
+/// Adapts the `part_one` / `part_two` functions of a day binary to [`Solution`].
+pub struct Day<A, B> {
+    year: i16,
+    day: u8,
+    part_one: fn(&str) -> A,
+    part_two: fn(&str) -> B,
+}
+
+impl<A, B> Day<A, B> {
+    pub fn new(year: i16, day: u8, part_one: fn(&str) -> A, part_two: fn(&str) -> B) -> Self {
+        Day {
+            year,
+            day,
+            part_one,
+            part_two,
+        }
+    }
+}
+
+impl<A: PartOutput, B: PartOutput> Solution for Day<A, B> {
+    fn year(&self) -> i16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn part_one(&self, input: &str) -> Result<Answer, AocError> {
+        (self.part_one)(input).into_result()
+    }
+
+    fn part_two(&self, input: &str) -> Result<Answer, AocError> {
+        (self.part_two)(input).into_result()
+    }
+}
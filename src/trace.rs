@@ -0,0 +1,56 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::cell::Cell;
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether [`trace!`](crate::trace!) prints, i.e. the day runs with `--trace`.
+pub fn enabled() -> bool {
+    ENABLED.with(Cell::get)
+}
+
+/// Runs `f` with [`trace!`](crate::trace!) printing on this thread.
+pub fn with_tracing<T>(f: impl FnOnce() -> T) -> T {
+    // stops tracing even if `f` panics.
+    struct Reset;
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            ENABLED.with(|enabled| enabled.set(false));
+        }
+    }
+
+    ENABLED.with(|enabled| enabled.set(true));
+    let _reset = Reset;
+    f()
+}
+
+/// Prints a line to stderr like `eprintln!`, but only if the day runs with `--trace`.
+/// The arguments are not evaluated otherwise, so tracing every step of a solution is free.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled() {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_tracing() {
+        assert!(!enabled());
+        assert!(with_tracing(enabled));
+        assert!(!enabled());
+
+        let traced = std::panic::catch_unwind(|| with_tracing(|| panic!("boom")));
+        assert!(traced.is_err());
+        assert!(!enabled());
+    }
+}
@@ -0,0 +1,302 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::cell::RefCell;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::helpers::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const GREY: Rgb = Rgb(90, 90, 90);
+    pub const WHITE: Rgb = Rgb(230, 230, 230);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(64, 160, 43);
+    pub const YELLOW: Rgb = Rgb(223, 142, 29);
+}
+
+/// A cell of a [`Frame`]: terminals print its glyph, images fill a square with its colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    pub const EMPTY: Cell = Cell::new(' ', Rgb::BLACK);
+
+    pub const fn new(glyph: char, color: Rgb) -> Self {
+        Cell { glyph, color }
+    }
+}
+
+/// Colours the usual puzzle characters: blanks black, `.` grey, `#` white, anything else yellow.
+impl From<char> for Cell {
+    fn from(glyph: char) -> Self {
+        let color = match glyph {
+            ' ' => Rgb::BLACK,
+            '.' => Rgb::GREY,
+            '#' => Rgb::WHITE,
+            _ => Rgb::YELLOW,
+        };
+        Cell::new(glyph, color)
+    }
+}
+
+pub type Frame = Grid<Cell>;
+
+/// Implemented by the state of a simulation that can be drawn, see [`emit`].
+pub trait Visualize {
+    fn frame(&self) -> Frame;
+}
+
+pub trait Renderer {
+    fn render(&mut self, frame: &Frame) -> io::Result<()>;
+}
+
+/// Redraws every frame in place on a terminal, waiting between frames to keep to a frame rate.
+pub struct Terminal<W: Write> {
+    out: W,
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Terminal {
+            out,
+            interval: Duration::from_secs(1) / fps.max(1),
+            last: None,
+        }
+    }
+}
+
+impl<W: Write> Renderer for Terminal<W> {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        if let Some(last) = self.last {
+            thread::sleep(self.interval.saturating_sub(last.elapsed()));
+        }
+        self.last = Some(Instant::now());
+
+        let mut s = String::from("\x1b[2J\x1b[H");
+        for row in frame.rows() {
+            let mut color = None;
+            for cell in row {
+                if color != Some(cell.color) {
+                    let Rgb(r, g, b) = cell.color;
+                    s.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                    color = Some(cell.color);
+                }
+                s.push(cell.glyph);
+            }
+            s.push_str(crate::ANSI_RESET);
+            s.push('\n');
+        }
+
+        self.out.write_all(s.as_bytes())?;
+        self.out.flush()
+    }
+}
+
+/// Writes every frame to `<dir>/frame-00000.ppm`, `<dir>/frame-00001.ppm`, ...
+/// with every cell drawn as a `scale` × `scale` square.
+pub struct PpmFrames {
+    dir: PathBuf,
+    scale: usize,
+    count: usize,
+}
+
+impl PpmFrames {
+    pub fn new(dir: PathBuf, scale: usize) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(PpmFrames {
+            dir,
+            scale: scale.max(1),
+            count: 0,
+        })
+    }
+}
+
+impl Renderer for PpmFrames {
+    fn render(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self.dir.join(format!("frame-{:05}.ppm", self.count));
+        fs::write(path, ppm(frame, self.scale))?;
+        self.count += 1;
+        Ok(())
+    }
+}
+
+/// Encodes a frame as a binary PPM image.
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    image.reserve(width * height * 3);
+
+    for row in frame.rows() {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|cell| {
+                let Rgb(r, g, b) = cell.color;
+                [r, g, b].repeat(scale)
+            })
+            .collect();
+        for _ in 0..scale {
+            image.extend_from_slice(&line);
+        }
+    }
+
+    image
+}
+
+/// Where `--visualize` sends the frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Config {
+    Terminal { fps: u32 },
+    Ppm { dir: PathBuf, scale: usize },
+}
+
+impl Config {
+    /// Reads `--visualize [--fps <n>]` or `--visualize-ppm <dir> [--scale <n>]`.
+    /// Returns `None` if neither is present.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        if let Some(dir) = args.opt_value_from_str("--visualize-ppm")? {
+            return Ok(Some(Config::Ppm {
+                dir,
+                scale: args.opt_value_from_str("--scale")?.unwrap_or(4),
+            }));
+        }
+
+        if !args.contains("--visualize") {
+            return Ok(None);
+        }
+
+        Ok(Some(Config::Terminal {
+            fps: args.opt_value_from_str("--fps")?.unwrap_or(30),
+        }))
+    }
+
+    /// A renderer for a part. Images go to `<dir>/<year>-<day>/part<part>/`.
+    pub fn renderer(&self, year: i16, day: u8, part: u8) -> io::Result<Box<dyn Renderer>> {
+        Ok(match self {
+            Config::Terminal { fps } => Box::new(Terminal::new(io::stderr(), *fps)),
+            Config::Ppm { dir, scale } => Box::new(PpmFrames::new(
+                dir.join(format!("{}-{:02}", year, day))
+                    .join(format!("part{}", part)),
+                *scale,
+            )?),
+        })
+    }
+}
+
+thread_local! {
+    static RENDERER: RefCell<Option<Box<dyn Renderer>>> = RefCell::new(None);
+}
+
+/// Whether frames passed to [`emit`] are rendered.
+pub fn enabled() -> bool {
+    RENDERER.with(|renderer| renderer.borrow().is_some())
+}
+
+/// Renders the current state of a simulation, if the day runs with `--visualize`.
+/// Otherwise this does nothing, so it can be called unconditionally from a day's step function.
+pub fn emit(state: &impl Visualize) {
+    RENDERER.with(|renderer| {
+        let mut renderer = renderer.borrow_mut();
+        if let Some(r) = renderer.as_mut() {
+            if let Err(e) = r.render(&state.frame()) {
+                eprintln!(
+                    "Failed to render a frame, stopping the visualization: {}",
+                    e
+                );
+                *renderer = None;
+            }
+        }
+    });
+}
+
+/// Runs `f` with every frame passed to [`emit`] on this thread going to `renderer`.
+pub fn with_renderer<T>(renderer: Box<dyn Renderer>, f: impl FnOnce() -> T) -> T {
+    // removes the renderer even if `f` panics.
+    struct Reset;
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            RENDERER.with(|renderer| renderer.borrow_mut().take());
+        }
+    }
+
+    RENDERER.with(|r| *r.borrow_mut() = Some(renderer));
+    let _reset = Reset;
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+
+    struct Counter(usize);
+
+    impl Visualize for Counter {
+        fn frame(&self) -> Frame {
+            Grid::new(self.0, 1, Cell::from('#'))
+        }
+    }
+
+    struct Recorder(Rc<RefCell<Vec<usize>>>);
+
+    impl Renderer for Recorder {
+        fn render(&mut self, frame: &Frame) -> io::Result<()> {
+            self.0.borrow_mut().push(frame.width());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_emit() {
+        let widths = Rc::new(RefCell::new(vec![]));
+
+        emit(&Counter(1));
+        with_renderer(Box::new(Recorder(Rc::clone(&widths))), || {
+            assert!(enabled());
+            emit(&Counter(2));
+            emit(&Counter(3));
+        });
+        emit(&Counter(4));
+
+        assert!(!enabled());
+        assert_eq!(*widths.borrow(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_terminal() {
+        let frame = Grid::parse("#.\n.#", |c| Ok(Cell::from(c))).unwrap();
+        let mut out = vec![];
+        Terminal::new(&mut out, 1000).render(&frame).unwrap();
+
+        let s = String::from_utf8(out).unwrap();
+        assert!(s.starts_with("\x1b[2J\x1b[H\x1b[38;2;230;230;230m#\x1b[38;2;90;90;90m."));
+        assert_eq!(s.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_ppm() {
+        let frame = Grid::from_vec(2, vec![Cell::from('#'), Cell::EMPTY]).unwrap();
+        let image = ppm(&frame, 2);
+
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(
+            &image[header.len()..],
+            [[230; 6], [0; 6], [230; 6], [0; 6]].concat()
+        );
+    }
+}